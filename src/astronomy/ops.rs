@@ -4,11 +4,16 @@
 // Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
 //
 
+#[cfg(feature = "nutation-cache")]
+use std::cell::RefCell;
+
 use chrono::{DateTime, Duration, Utc};
 
+use crate::astronomy::solar::SolarCoordinates;
 use crate::astronomy::unit::{Angle, Coordinates};
 use crate::astronomy::unit::{Normalize, Stride};
 use crate::models::shafaq::Shafaq;
+use crate::models::calendar::Calendar;
 use crate::models::rounding::Rounding;
 
 // The geometric mean longitude of the sun.
@@ -104,6 +109,49 @@ pub fn apparent_obliquity_of_the_ecliptic(
     )
 }
 
+// The apparent right ascension of the sun.
+fn apparent_right_ascension(julian_century: f64) -> Angle {
+    // Equation from Astronomical Algorithms page 165
+    let apparent_longitude =
+        apparent_solar_longitude(julian_century, mean_solar_longitude(julian_century)).radians();
+    let obliquity = apparent_obliquity_of_the_ecliptic(
+        julian_century,
+        mean_obliquity_of_the_ecliptic(julian_century),
+    )
+    .radians();
+
+    Angle::from_radians(
+        (obliquity.cos() * apparent_longitude.sin()).atan2(apparent_longitude.cos()),
+    )
+    .unwound()
+}
+
+/// The declination of the sun on the given Julian day, the angle
+/// between the rays of the sun and the plane of the Earth's equator.
+pub fn solar_declination(julian_day: f64) -> Angle {
+    SolarCoordinates::new(julian_day).declination()
+}
+
+/// The equation of time on the given Julian day, in minutes. This is
+/// how far a sundial (apparent solar time) is ahead of the clock (mean
+/// solar time).
+pub fn equation_of_time(julian_day: f64) -> f64 {
+    // Equation from Astronomical Algorithms page 183
+    let julian_century = julian_century(julian_day);
+    let mean_longitude = mean_solar_longitude(julian_century);
+    let right_ascension = apparent_right_ascension(julian_century);
+    let nutation = nutation(julian_century);
+    let true_obliquity =
+        Angle::new(mean_obliquity_of_the_ecliptic(julian_century).degrees + nutation.obliquity);
+    let equation = Angle::new(
+        mean_longitude.degrees - 0.0057183 - right_ascension.degrees
+            + (nutation.longitude * true_obliquity.radians().cos()),
+    );
+
+    // Each degree of the hour angle is four minutes of time.
+    equation.quadrant_shifted().degrees * 4.0
+}
+
 // Mean sidereal time, the hour angle of the vernal equinox.
 pub fn mean_sidereal_time(julian_century: f64) -> Angle {
     // Equation from Astronomical Algorithms page 165
@@ -145,6 +193,79 @@ pub fn nutation_in_obliquity(
     term1 + term2 + term3 - term4
 }
 
+// Nutation in longitude and in obliquity, in degrees.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Nutation {
+    pub longitude: f64,
+    pub obliquity: f64,
+}
+
+// Nutation in longitude and in obliquity for the given julian century,
+// memoized when the `nutation-cache` feature is enabled.
+pub fn nutation(julian_century: f64) -> Nutation {
+    #[cfg(feature = "nutation-cache")]
+    {
+        cached_nutation(julian_century)
+    }
+
+    #[cfg(not(feature = "nutation-cache"))]
+    {
+        calculate_nutation(julian_century)
+    }
+}
+
+// Nutation in longitude and in obliquity for the given julian century.
+pub fn calculate_nutation(julian_century: f64) -> Nutation {
+    let solar_longitude = mean_solar_longitude(julian_century);
+    let lunar_longitude = mean_lunar_longitude(julian_century);
+    let ascending_node = ascending_lunar_node_longitude(julian_century);
+
+    Nutation {
+        longitude: nutation_in_longitude(solar_longitude, lunar_longitude, ascending_node),
+        obliquity: nutation_in_obliquity(solar_longitude, lunar_longitude, ascending_node),
+    }
+}
+
+// Number of entries kept by the nutation cache; a day's calculation
+// uses the day before and after, so a small cache is sufficient.
+#[cfg(feature = "nutation-cache")]
+const NUTATION_CACHE_SIZE: usize = 16;
+
+#[cfg(feature = "nutation-cache")]
+thread_local! {
+    static NUTATION_CACHE: RefCell<Vec<(u64, Nutation)>> =
+        RefCell::new(Vec::with_capacity(NUTATION_CACHE_SIZE));
+}
+
+// Memoized version of `calculate_nutation`, keeping the most recently
+// used values. The cache is keyed on the exact julian century, so the
+// results are identical to the ones calculated by `calculate_nutation`.
+#[cfg(feature = "nutation-cache")]
+pub fn cached_nutation(julian_century: f64) -> Nutation {
+    let key = julian_century.to_bits();
+
+    NUTATION_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+
+        if let Some(index) = cache.iter().position(|(cached_key, _)| *cached_key == key) {
+            let entry = cache.remove(index);
+            cache.push(entry);
+
+            return entry.1;
+        }
+
+        let value = calculate_nutation(julian_century);
+
+        if cache.len() == NUTATION_CACHE_SIZE {
+            cache.remove(0);
+        }
+
+        cache.push((key, value));
+
+        value
+    })
+}
+
 pub fn altitude_of_celestial_body(
     observer_latitude: Angle,
     declination: Angle,
@@ -280,6 +401,17 @@ pub fn interpolate_angles(
 
 // The Julian Day for the given Gregorian date.
 pub fn julian_day(year: i32, month: i32, day: i32, hours: f64) -> f64 {
+    julian_day_for_calendar(Calendar::Gregorian, year, month, day, hours)
+}
+
+// The Julian day for a date in the given calendar.
+pub fn julian_day_for_calendar(
+    calendar: Calendar,
+    year: i32,
+    month: i32,
+    day: i32,
+    hours: f64,
+) -> f64 {
     // Equation from Astronomical Algorithms page 60
 
     // NOTE: Casting to i32 is done intentionally for the purpose of decimal truncation
@@ -288,8 +420,16 @@ pub fn julian_day(year: i32, month: i32, day: i32, hours: f64) -> f64 {
     let adjusted_month: i32 = if month > 2 { month } else { month + 12 };
     let adjusted_day: f64 = (day as f64) + (hours / 24.0);
 
-    let a: i32 = adjusted_year / 100;
-    let b: i32 = 2 - a + (a / 4);
+    // The correction for the Gregorian reform is
+    // not used for dates in the Julian calendar.
+    let b: i32 = match calendar {
+        Calendar::Gregorian => {
+            let a: i32 = adjusted_year / 100;
+
+            2 - a + (a / 4)
+        }
+        Calendar::Julian => 0,
+    };
 
     let i0: i32 = (365.25 * ((adjusted_year as f64) + 4716.0)) as i32;
     let i1: i32 = (30.6001 * ((adjusted_month as f64) + 1.0)) as i32;
@@ -443,18 +583,19 @@ pub fn days_since_solstice(day_of_year: u32, year: u32, latitude: f64) -> u32 {
         }
     } else {
         let southern_offset = if is_leap_year(year) { 173 } else { 172 };
-        (day_of_year - southern_offset) + days_in_year
-    }
-}
 
-pub fn adjust_time(date: &DateTime<Utc>, minutes: i64) -> DateTime<Utc> {
-    date.checked_add_signed(Duration::seconds(minutes * 60))
-        .unwrap()
+        if day_of_year >= southern_offset {
+            day_of_year - southern_offset
+        } else {
+            day_of_year + days_in_year - southern_offset
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use spectral::prelude::*;
 
     #[test]
     fn calculate_julian_day() {
@@ -590,6 +731,102 @@ mod tests {
         assert_eq!(nutation_obliq, -0.000092747500292341556);
     }
 
+    #[test]
+    fn calculate_nutation_terms() {
+        let julian_century = julian_century(julian_day(1992, 10, 13, 0.0));
+        let nutation = nutation(julian_century);
+
+        assert_eq!(nutation.longitude, 0.0044525358169686564);
+        assert_eq!(nutation.obliquity, -0.000092747500292341556);
+        assert_eq!(nutation, calculate_nutation(julian_century));
+    }
+
+    #[cfg(feature = "nutation-cache")]
+    #[test]
+    fn calculate_cached_nutation() {
+        for day in 0..(NUTATION_CACHE_SIZE * 2) {
+            let julian_day = julian_day(1992, 10, 13, 0.0) + (day as f64);
+            let julian_century = julian_century(julian_day);
+            let expected = calculate_nutation(julian_century);
+
+            assert_eq!(cached_nutation(julian_century), expected);
+            assert_eq!(cached_nutation(julian_century), expected);
+        }
+
+        let julian_century = julian_century(julian_day(1992, 10, 13, 0.0));
+        let nutation = cached_nutation(julian_century);
+
+        assert_eq!(nutation.longitude, 0.0044525358169686564);
+        assert_eq!(nutation.obliquity, -0.000092747500292341556);
+    }
+
+    // Run with `cargo test --release --features nutation-cache -- --ignored
+    // --nocapture` to compare the cached and calculated nutation for the
+    // lookups made by a year of daily solar times (the day before, the day,
+    // and the day after).
+    #[cfg(feature = "nutation-cache")]
+    #[test]
+    #[ignore]
+    fn benchmark_cached_nutation() {
+        use std::time::Instant;
+
+        let start_day = julian_day(2020, 1, 1, 0.0);
+        let centuries: Vec<f64> = (0..366)
+            .flat_map(|day| (-1..=1).map(move |offset| start_day + (day + offset) as f64))
+            .map(julian_century)
+            .collect();
+        let time = |nutation: fn(f64) -> Nutation| {
+            let start = Instant::now();
+            let mut total = 0.0;
+
+            for _ in 0..100 {
+                for julian_century in &centuries {
+                    total += nutation(*julian_century).longitude;
+                }
+            }
+
+            (start.elapsed(), total)
+        };
+        let (calculated, calculated_total) = time(calculate_nutation);
+        let (cached, cached_total) = time(cached_nutation);
+
+        println!("calculated: {:?}, cached: {:?}", calculated, cached);
+        assert_eq!(cached_total, calculated_total);
+    }
+
+    #[test]
+    fn calculate_solar_declination() {
+        // values from Astronomical Algorithms page 185
+        let declination = solar_declination(2448908.5);
+
+        assert_that!(declination.degrees).is_close_to(-7.78507, 0.001);
+        assert_that!(solar_declination(julian_day(2021, 6, 21, 12.0)).degrees)
+            .is_close_to(23.44, 0.01);
+    }
+
+    #[test]
+    fn calculate_equation_of_time() {
+        // values from Astronomical Algorithms page 185
+        assert_that!(equation_of_time(2448908.5)).is_close_to(13.71, 0.02);
+        assert_that!(equation_of_time(julian_day(2021, 11, 3, 12.0))).is_close_to(16.45, 0.05);
+        assert_that!(equation_of_time(julian_day(2021, 2, 11, 12.0))).is_close_to(-14.2, 0.1);
+    }
+
+    #[test]
+    fn calculate_days_since_solstice() {
+        // The northern year starts at the December solstice.
+        assert_eq!(days_since_solstice(355, 2021, 35.0), 0);
+        assert_eq!(days_since_solstice(1, 2021, 35.0), 11);
+
+        // The southern year starts at the June solstice.
+        assert_eq!(days_since_solstice(172, 2021, -33.9), 0);
+        assert_eq!(days_since_solstice(355, 2021, -33.9), 183);
+        assert_eq!(days_since_solstice(1, 2021, -33.9), 194);
+        assert_eq!(days_since_solstice(171, 2021, -33.9), 364);
+        assert_eq!(days_since_solstice(173, 2020, -33.9), 0);
+        assert_eq!(days_since_solstice(172, 2020, -33.9), 365);
+    }
+
     #[test]
     fn calculate_altitude_of_celestial_body() {
         let coordinates = Coordinates::new(35.783333333333331, -78.650000000000006);
@@ -4,32 +4,143 @@
 // Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
 //
 
+use std::f64::consts::PI;
 use std::fmt;
 
 use crate::astronomy::unit::{Angle, Coordinates};
 
+// The 16 points of the compass, clockwise from North.
+const COMPASS_POINTS: [&str; 16] = [
+    "N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE", "S", "SSW", "SW", "WSW", "W", "WNW", "NW",
+    "NNW",
+];
+
 #[derive(Debug)]
 pub struct Qiblah(f64);
 
+/// The direction of and distance to the Kaaba
+/// from a location, bundled together.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct QiblahInfo {
+    /// The great circle direction, in degrees from North.
+    pub bearing: f64,
+    /// The great circle distance, in kilometers.
+    pub distance_km: f64,
+    /// The closest of the 16 points of the compass, e.g. `"NE"`.
+    pub cardinal: &'static str,
+}
+
+impl QiblahInfo {
+    /// Returns the information as a JSON object.
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"bearing\":{},\"distance_km\":{},\"cardinal\":\"{}\"}}",
+            self.bearing, self.distance_km, self.cardinal
+        )
+    }
+}
+
 impl Qiblah {
+    /// The direction, in degrees from North, of the great circle
+    /// (shortest path) from the given location toward the Kaaba.
+    /// This is the direction that should be used for prayer.
     pub fn new(location_coordinates: Coordinates) -> Self {
+        Qiblah::toward(&location_coordinates, &Coordinates::makkah())
+    }
+
+    /// The direction, in degrees from North, from the given
+    /// location toward the target location (along a great circle).
+    pub fn toward(location: &Coordinates, target: &Coordinates) -> Self {
         // Equation from "Spherical Trigonometry For the use
         // of colleges and schools" page 50
-        let makkah_coordinates = Coordinates::new(21.4225241, 39.8261818);
-        let term1 = (makkah_coordinates.longitude_angle().radians()
-            - location_coordinates.longitude_angle().radians())
-        .sin();
-        let term2 = makkah_coordinates.latitude_angle().radians().tan()
-            * location_coordinates.latitude_angle().radians().cos();
-        let term3 = (makkah_coordinates.longitude_angle().radians()
-            - location_coordinates.longitude_angle().radians())
-        .cos()
-            * location_coordinates.latitude_angle().radians().sin();
+        let term1 =
+            (target.longitude_angle().radians() - location.longitude_angle().radians()).sin();
+        let term2 =
+            target.latitude_angle().radians().tan() * location.latitude_angle().radians().cos();
+        let term3 = (target.longitude_angle().radians() - location.longitude_angle().radians())
+            .cos()
+            * location.latitude_angle().radians().sin();
         let term4 = term1.atan2(term2 - term3);
 
         Qiblah(Angle::from_radians(term4).unwound().degrees)
     }
-    
+
+    /// The direction, in degrees from magnetic North, of the great circle
+    /// from the given location toward the Kaaba, for use with a magnetic
+    /// compass. The magnetic declination (positive when magnetic North is
+    /// east of true North) must be supplied, e.g. from the World Magnetic
+    /// Model; the magnetic model is not part of this library.
+    pub fn magnetic(location_coordinates: Coordinates, declination_degrees: f64) -> Self {
+        let bearing = Qiblah::new(location_coordinates).value() - declination_degrees;
+
+        Qiblah(Angle::new(bearing).unwound().degrees)
+    }
+
+    /// The signed degrees, from −180° to 180°, that a device with the given
+    /// heading (in degrees from North) must turn to face the Qiblah from the
+    /// given location. Positive values are clockwise (turning right).
+    pub fn alignment_error(location: &Coordinates, device_heading: f64) -> f64 {
+        let qiblah = Qiblah::new(*location).value();
+
+        Angle::new(device_heading)
+            .shortest_difference(Angle::new(qiblah))
+            .degrees
+    }
+
+    /// The constant bearing, in degrees from North, of the rhumb line
+    /// (loxodrome) from the given location toward the Kaaba. Unlike the
+    /// great circle, following this bearing never requires changing
+    /// course, which is what older compasses and Mercator maps show.
+    /// The two agree near Makkah and drift apart further away.
+    pub fn rhumb(location_coordinates: Coordinates) -> Self {
+        let makkah = Coordinates::makkah();
+        let latitude = location_coordinates.latitude_angle().radians();
+        let target_latitude = makkah.latitude_angle().radians();
+        let longitude_delta = Angle::new(makkah.longitude - location_coordinates.longitude)
+            .quadrant_shifted()
+            .radians();
+        let projected_delta = ((PI / 4.0 + target_latitude / 2.0).tan()
+            / (PI / 4.0 + latitude / 2.0).tan())
+        .ln();
+
+        Qiblah(
+            Angle::from_radians(longitude_delta.atan2(projected_delta))
+                .unwound()
+                .degrees,
+        )
+    }
+
+    /// The unit vector, in local east-north-up (ENU) components, of the
+    /// great circle direction from the given location toward the Kaaba.
+    /// The first component points east, the second north and the third
+    /// up (away from the center of the Earth), forming a right-handed
+    /// frame. Since the great circle leaves the location along the horizon,
+    /// the up component is always zero.
+    pub fn direction_vector(location: &Coordinates) -> [f64; 3] {
+        let bearing = Angle::new(Qiblah::new(*location).value()).radians();
+
+        [bearing.sin(), bearing.cos(), 0.0]
+    }
+
+    /// The direction, distance, and closest compass
+    /// point from the given location toward the Kaaba.
+    pub fn info(location: Coordinates) -> QiblahInfo {
+        let qiblah = Qiblah::new(location);
+
+        QiblahInfo {
+            bearing: qiblah.value(),
+            distance_km: location.distance_to(&Coordinates::makkah()),
+            cardinal: qiblah.cardinal(),
+        }
+    }
+
+    /// The closest of the 16 points of the compass to the direction.
+    pub fn cardinal(&self) -> &'static str {
+        let index = (Angle::new(self.0).unwound().degrees / 22.5).round() as usize % 16;
+
+        COMPASS_POINTS[index]
+    }
+
     pub fn value(&self) -> f64 {
         self.0
     }
@@ -143,6 +254,80 @@ mod tests {
         assert_that!(qiblah.value()).is_close_to(295.1442983825265, 0.0000001f64);
     }
     
+    #[test]
+    fn direction_from_medina_toward_jerusalem() {
+        let medina = Coordinates::new(24.4672, 39.6111);
+        let jerusalem = Coordinates::new(31.7780, 35.2354);
+        let direction = Qiblah::toward(&medina, &jerusalem);
+
+        assert_that!(direction.value()).is_close_to(333.1782323, 0.0000001f64);
+    }
+
+    #[test]
+    fn magnetic_qiblah_direction() {
+        let nyc = Coordinates::new(40.7128, -74.0059);
+        let sf = Coordinates::new(37.7749, -122.4194);
+        let magnetic_nyc = Qiblah::magnetic(nyc, -12.9);
+        let magnetic_sf = Qiblah::magnetic(sf, 25.0);
+
+        assert_that!(magnetic_nyc.value()).is_close_to(58.4817635 + 12.9, 0.0000001f64);
+        assert_that!(magnetic_sf.value())
+            .is_close_to(18.843822245692426 - 25.0 + 360.0, 0.0000001f64);
+        assert_eq!(Qiblah::magnetic(nyc, 0.0).value(), Qiblah::new(nyc).value());
+    }
+
+    #[test]
+    fn alignment_error_of_device_heading() {
+        let nyc = Coordinates::new(40.7128, -74.0059);
+        let qiblah = Qiblah::new(nyc).value();
+
+        assert_that!(Qiblah::alignment_error(&nyc, qiblah)).is_close_to(0.0, 0.0000001f64);
+        assert_that!(Qiblah::alignment_error(&nyc, qiblah - 90.0)).is_close_to(90.0, 0.0000001f64);
+        assert_that!(Qiblah::alignment_error(&nyc, qiblah + 90.0)).is_close_to(-90.0, 0.0000001f64);
+        assert_that!(Qiblah::alignment_error(&nyc, 350.0)).is_close_to(qiblah + 10.0, 0.0000001f64);
+    }
+
+    #[test]
+    fn rhumb_line_direction_from_oslo_norway() {
+        let oslo = Coordinates::new(59.9139, 10.7522);
+        let great_circle = Qiblah::new(oslo);
+        let rhumb_line = Qiblah::rhumb(oslo);
+
+        assert_that!(rhumb_line.value()).is_close_to(151.4085129, 0.0000001f64);
+        assert!((rhumb_line.value() - great_circle.value()).abs() > 10.0);
+    }
+
+    #[test]
+    fn qiblah_direction_vector_from_london_united_kingdom() {
+        let london = Coordinates::new(51.5074, -0.1278);
+        let [east, north, up] = Qiblah::direction_vector(&london);
+        let bearing = Angle::from_radians(east.atan2(north)).unwound();
+
+        assert_that!((east * east + north * north + up * up).sqrt()).is_close_to(1.0, 0.0000001f64);
+        assert_that!(bearing.degrees).is_close_to(Qiblah::new(london).value(), 0.0000001f64);
+        assert_eq!(up, 0.0);
+    }
+
+    #[test]
+    fn qiblah_info_from_nyc_in_north_america() {
+        let nyc = Coordinates::new(40.7128, -74.0059);
+        let info = Qiblah::info(nyc);
+
+        assert_eq!(info.bearing, Qiblah::new(nyc).value());
+        assert!(info.distance_km > 10_000.0 && info.distance_km < 10_500.0);
+        assert_eq!(info.cardinal, "ENE");
+        assert!(info.to_json().starts_with("{\"bearing\":58.48"));
+        assert!(info.to_json().ends_with("\"cardinal\":\"ENE\"}"));
+    }
+
+    #[test]
+    fn qiblah_cardinal_direction() {
+        assert_eq!(Qiblah(0.0).cardinal(), "N");
+        assert_eq!(Qiblah(350.0).cardinal(), "N");
+        assert_eq!(Qiblah(118.9).cardinal(), "ESE");
+        assert_eq!(Qiblah(277.5).cardinal(), "W");
+    }
+
     #[test]
     fn qiblah_direction_display() {
         let nyc = Coordinates::new(40.7128, -74.0059);
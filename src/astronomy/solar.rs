@@ -4,11 +4,23 @@
 // Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
 //
 
-use chrono::{DateTime, Datelike, TimeZone, Utc};
+use std::error::Error;
+use std::fmt;
+
+use chrono::{DateTime, Datelike, Duration, TimeZone, Timelike, Utc};
 
 use crate::astronomy::ops;
 use crate::astronomy::unit::Stride;
 use crate::astronomy::unit::{Angle, Coordinates};
+use crate::models::twilight_status::TwilightStatus;
+
+// How close, in degrees, a latitude must be to ±90° to be
+// treated as a pole, where the hour angles are undefined.
+const POLE_LATITUDE_EPSILON: f64 = 1e-6;
+
+// The number of times the altitude for a precise Asr is
+// calculated again, which is enough for it to converge.
+const PRECISE_ASR_ITERATIONS: usize = 2;
 
 #[derive(PartialEq, Debug, Copy, Clone)]
 pub struct SolarCoordinates {
@@ -25,25 +37,16 @@ pub struct SolarCoordinates {
 }
 
 impl SolarCoordinates {
-    fn new(julian_day: f64) -> Self {
+    pub fn new(julian_day: f64) -> Self {
         let julian_century = ops::julian_century(julian_day);
         let mean_solar_longitude = ops::mean_solar_longitude(julian_century);
-        let mean_lunar_longitude = ops::mean_lunar_longitude(julian_century);
-        let ascending_lunar_node = ops::ascending_lunar_node_longitude(julian_century);
         let apparent_solar_longitude =
             ops::apparent_solar_longitude(julian_century, mean_solar_longitude).radians();
 
         let mean_sidereal_time = ops::mean_sidereal_time(julian_century);
-        let nutation_longitude = ops::nutation_in_longitude(
-            mean_solar_longitude,
-            mean_lunar_longitude,
-            ascending_lunar_node,
-        );
-        let nutation_obliq = ops::nutation_in_obliquity(
-            mean_solar_longitude,
-            mean_lunar_longitude,
-            ascending_lunar_node,
-        );
+        let nutation = ops::nutation(julian_century);
+        let nutation_longitude = nutation.longitude;
+        let nutation_obliq = nutation.obliquity;
 
         let mean_obliq_ecliptic = ops::mean_obliquity_of_the_ecliptic(julian_century);
         let apparent_obliq_ecliptic =
@@ -77,8 +80,34 @@ impl SolarCoordinates {
             apparent_sidereal_time: apparent_sidereal_time,
         }
     }
+
+    pub fn declination(&self) -> Angle {
+        self.declination
+    }
+}
+
+/// The reasons the times of the sun can not
+/// be calculated for a given day and location.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum SolarError {
+    /// The sun does not rise during the day.
+    PolarNight,
+
+    /// The sun does not set during the day.
+    MidnightSun,
+}
+
+impl fmt::Display for SolarError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolarError::PolarNight => write!(f, "The sun does not rise on the given day."),
+            SolarError::MidnightSun => write!(f, "The sun does not set on the given day."),
+        }
+    }
 }
 
+impl Error for SolarError {}
+
 // Solar Time
 #[derive(Debug, Copy, Clone)]
 pub struct SolarTime {
@@ -94,7 +123,21 @@ pub struct SolarTime {
 }
 
 impl SolarTime {
-    pub fn new(date: DateTime<Utc>, coordinates: Coordinates) -> SolarTime {
+    /// Calculates the times of the sun for the given day and location.
+    /// Fails when the sun does not rise or does not set on that day.
+    pub fn new(date: DateTime<Utc>, coordinates: Coordinates) -> Result<SolarTime, SolarError> {
+        SolarTime::with_altitude(date, coordinates, Angle::SUN_HORIZON_ALTITUDE)
+    }
+
+    /// Like [new](#method.new), but sunrise and sunset are the times at
+    /// which the sun reaches the given altitude instead of −50′, which
+    /// accounts for refraction and the upper limb of the sun. For example,
+    /// 0° is the center of the sun crossing the geometric horizon.
+    pub fn with_altitude(
+        date: DateTime<Utc>,
+        coordinates: Coordinates,
+        solar_altitude: Angle,
+    ) -> Result<SolarTime, SolarError> {
         // All calculation need to occur at 0h0m UTC
         let today = Utc
             .ymd(date.year(), date.month(), date.day())
@@ -104,7 +147,23 @@ impl SolarTime {
         let prev_solar = SolarCoordinates::new(yesterday.julian_day());
         let solar = SolarCoordinates::new(today.julian_day());
         let next_solar = SolarCoordinates::new(tomorrow.julian_day());
-        let solar_altitude = Angle::new(-50.0 / 60.0);
+
+        // Altitude at the upper culmination of the sun.
+        let highest_altitude = 90.0 - (coordinates.latitude - solar.declination.degrees).abs();
+        let polar_error = if highest_altitude < solar_altitude.degrees {
+            SolarError::PolarNight
+        } else {
+            SolarError::MidnightSun
+        };
+
+        // At the poles the sun circles the sky at an almost constant
+        // altitude, so it stays either above or below the horizon all day.
+        if 90.0 - coordinates.latitude.abs() < POLE_LATITUDE_EPSILON {
+            return Err(polar_error);
+        } else {
+            // Nothing to do.
+        }
+
         let approx_transit = ops::approximate_transit(
             coordinates.longitude_angle(),
             solar.apparent_sidereal_time,
@@ -145,20 +204,48 @@ impl SolarTime {
             next_solar.declination,
         );
 
-        SolarTime {
-            date: date,
-            observer: coordinates,
-            solar: solar,
-            transit: SolarTime::setting_hour(transit_time, &date).unwrap(),
-            sunrise: SolarTime::setting_hour(sunrise_time, &date).unwrap(),
-            sunset: SolarTime::setting_hour(sunset_time, &date).unwrap(),
-            prev_solar: prev_solar,
-            next_solar: next_solar,
-            approx_transit: approx_transit,
+        let sunrise = SolarTime::setting_hour(sunrise_time, &date);
+        let sunset = SolarTime::setting_hour(sunset_time, &date);
+
+        match (sunrise, sunset) {
+            (Some(sunrise), Some(sunset)) => Ok(SolarTime {
+                date: date,
+                observer: coordinates,
+                solar: solar,
+                transit: SolarTime::setting_hour(transit_time, &date).unwrap(),
+                sunrise: sunrise,
+                sunset: sunset,
+                prev_solar: prev_solar,
+                next_solar: next_solar,
+                approx_transit: approx_transit,
+            }),
+            _ => Err(polar_error),
         }
     }
 
-    pub fn time_for_solar_angle(&self, angle: Angle, after_transit: bool) -> DateTime<Utc> {
+    /// The declination of the sun at the start of the day, the angle
+    /// between the rays of the sun and the plane of the Earth's equator.
+    pub fn declination(&self) -> Angle {
+        self.solar.declination
+    }
+
+    /// The right ascension of the sun at the start of the day, the angular
+    /// distance on the celestial equator from the vernal equinox.
+    pub fn right_ascension(&self) -> Angle {
+        self.solar.right_ascension
+    }
+
+    /// The apparent sidereal time at the start of the day,
+    /// the hour angle of the vernal equinox.
+    pub fn sidereal_time(&self) -> Angle {
+        self.solar.apparent_sidereal_time
+    }
+
+    /// The time at which the sun reaches the given altitude, before or
+    /// after the transit. The altitude is negative below the horizon (e.g.
+    /// for twilight) and positive above it. Returns `None` when the sun never
+    /// reaches that altitude, e.g. the twilight angles at high latitudes in summer.
+    pub fn time_for_solar_angle(&self, angle: Angle, after_transit: bool) -> Option<DateTime<Utc>> {
         let hours = ops::corrected_hour_angle(
             self.approx_transit,
             angle,
@@ -173,68 +260,139 @@ impl SolarTime {
             self.next_solar.declination,
         );
 
-        SolarTime::setting_hour(hours, &self.date).unwrap()
+        SolarTime::setting_hour(hours, &self.date)
+    }
+
+    /// The time at which the sun reaches the given altitude above the
+    /// horizon, in the morning (rising) or in the afternoon (setting).
+    /// Returns `None` when the sun never gets that high on the day.
+    pub fn time_at_altitude(&self, altitude: Angle, morning: bool) -> Option<DateTime<Utc>> {
+        self.time_for_solar_angle(altitude, !morning)
+    }
+
+    /// How far apparent noon (the transit of the sun) is from 12:00 in
+    /// the nominal standard time of the observer, i.e. the timezone whose
+    /// central meridian is the multiple of 15° closest to the longitude.
+    /// It combines the equation of time with the longitude offset from that
+    /// meridian; positive values mean the sun transits after clock noon.
+    pub fn clock_noon_offset(&self) -> Duration {
+        let longitude = self.observer.longitude;
+        let central_meridian = (longitude / 15.0).round() * 15.0;
+        let equation_of_time = ops::equation_of_time(self.date.julian_day());
+        let minutes = (central_meridian - longitude) * 4.0 - equation_of_time;
+
+        Duration::seconds((minutes * 60.0).round() as i64)
     }
 
     pub fn afternoon(&self, shadow_length: f64) -> DateTime<Utc> {
-        let absolute_degrees = (self.observer.latitude - self.solar.declination.degrees).abs();
+        let angle = self.afternoon_angle(shadow_length, self.solar.declination);
+
+        // The altitude for Asr is always reached when the sun
+        // rises, since it is lower than the altitude at transit.
+        self.time_for_solar_angle(angle, true).unwrap()
+    }
+
+    /// Like [afternoon](#method.afternoon), but the altitude of the sun
+    /// is calculated again with the declination at the approximate time
+    /// of Asr, instead of the declination of the day, for a few iterations.
+    /// The difference is below a minute, and largest near the equinoxes
+    /// when the declination changes the fastest.
+    pub fn precise_afternoon(&self, shadow_length: f64) -> DateTime<Utc> {
+        let mut asr = self.afternoon(shadow_length);
+
+        for _ in 0..PRECISE_ASR_ITERATIONS {
+            let hours = asr.num_seconds_from_midnight() as f64 / 3600.0;
+            let julian_day =
+                ops::julian_day(asr.year(), asr.month() as i32, asr.day() as i32, hours);
+            let declination = SolarCoordinates::new(julian_day).declination;
+            let angle = self.afternoon_angle(shadow_length, declination);
+
+            asr = self.time_for_solar_angle(angle, true).unwrap();
+        }
+
+        asr
+    }
+
+    // The altitude of the sun when the shadow of an object is the
+    // given multiple of its length, plus the shadow at noon.
+    fn afternoon_angle(&self, shadow_length: f64, declination: Angle) -> Angle {
+        let absolute_degrees = (self.observer.latitude - declination.degrees).abs();
         let tangent = Angle::new(absolute_degrees);
         let inverse = shadow_length + tangent.radians().tan();
-        let angle = Angle::from_radians((1.0 / inverse).atan());
 
-        self.time_for_solar_angle(angle, true)
+        Angle::from_radians((1.0 / inverse).atan())
     }
 
+    /// Determines whether the sun reaches the given depression
+    /// angle (specified as a positive value below the horizon)
+    /// based on its highest and lowest altitudes for the day.
+    pub fn twilight_status(&self, depression: Angle) -> TwilightStatus {
+        let latitude = self.observer.latitude;
+        let declination = self.solar.declination.degrees;
+        let horizon = Angle::SUN_HORIZON_ALTITUDE.degrees;
+
+        // Altitudes at the upper and lower culmination of the sun.
+        let highest_altitude = 90.0 - (latitude - declination).abs();
+        let lowest_altitude = (latitude + declination).abs() - 90.0;
+
+        if highest_altitude < horizon {
+            TwilightStatus::PolarNight
+        } else if lowest_altitude > horizon {
+            TwilightStatus::MidnightSun
+        } else if lowest_altitude > -depression.degrees {
+            TwilightStatus::PersistentTwilight
+        } else {
+            TwilightStatus::Occurs
+        }
+    }
+
+    /// A summary of the sun for the day, with the times of sunrise,
+    /// solar noon (transit), and sunset in UTC, and the day length.
+    pub fn summary_string(&self) -> String {
+        let day_length = self
+            .sunset
+            .signed_duration_since(self.sunrise)
+            .num_seconds();
+
+        format!(
+            "Sunrise: {}\nSolar noon: {}\nSunset: {}\nDay length: {:02}:{:02}:{:02}",
+            self.sunrise.format("%H:%M:%S"),
+            self.transit.format("%H:%M:%S"),
+            self.sunset.format("%H:%M:%S"),
+            day_length / 3600,
+            day_length % 3600 / 60,
+            day_length % 60
+        )
+    }
+
+    /// Converts the given hours after midnight (UTC) of the date to a
+    /// time, rounded to the nearest second. Values before midnight or
+    /// after the end of the day fall on the previous or next date.
     fn setting_hour(value: f64, date: &DateTime<Utc>) -> Option<DateTime<Utc>> {
         let mut adjusted_time: Option<DateTime<Utc>> = None;
 
         if value.is_normal() {
-            let calculated_hours = value.floor();
-            let calculated_minutes = ((value - calculated_hours) * 60.0).floor();
-            let calculated_seconds =
-                ((value - (calculated_hours + calculated_minutes / 60.0)) * 60.0 * 60.0).floor();
-
-            let (adjusted_hour, adjusted_date) =
-                SolarTime::hour_adjustment(calculated_hours, &date);
-
-            // Round to the nearest minute
-            let adjusted_mins = (calculated_minutes + calculated_seconds / 60.0).round() as u32;
-            let adjusted_secs: u32 = 0;
-
-            let adjusted = Utc
-                .ymd(
-                    adjusted_date.year(),
-                    adjusted_date.month(),
-                    adjusted_date.day(),
-                )
-                .and_hms(adjusted_hour, adjusted_mins, adjusted_secs);
-
-            adjusted_time = Some(adjusted);
+            // Carries into the minutes, hours, and date are
+            // handled by adding the seconds to midnight.
+            let seconds = (value * 60.0 * 60.0).round() as i64;
+            let midnight = Utc
+                .ymd(date.year(), date.month(), date.day())
+                .and_hms(0, 0, 0);
+
+            adjusted_time = Some(midnight + Duration::seconds(seconds));
         } else {
             // Nothing to do.
         }
 
         adjusted_time
     }
-
-    fn hour_adjustment(calculated_hours: f64, date: &DateTime<Utc>) -> (u32, DateTime<Utc>) {
-        // Adjust the hour to be within 0..=23,
-        // wrapping around as needed; otherwise
-        // chrono method will panic.
-        if calculated_hours < 0.0 {
-            ((calculated_hours + 24.0) as u32, date.yesterday())
-        } else if calculated_hours >= 24.0 {
-            ((calculated_hours - 24.0) as u32, date.tomorrow())
-        } else {
-            (calculated_hours as u32, date.clone())
-        }
-    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::astronomy::ops;
+    use crate::models::rounding::Rounding;
     use chrono::{Datelike, Local, TimeZone, Utc};
 
     #[test]
@@ -245,6 +403,18 @@ mod tests {
         assert_eq!(solar.declination.degrees, -7.7850685152648795);
         assert_eq!(solar.right_ascension.degrees, 198.38082214251881);
         assert_eq!(solar.right_ascension.unwound().degrees, 198.38082214251881);
+        assert_eq!(ops::solar_declination(julian_day), solar.declination);
+    }
+
+    #[test]
+    fn solar_coordinates_from_solar_time() {
+        let date = Utc.ymd(1992, 10, 13).and_hms(0, 0, 0);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let solar_time = SolarTime::new(date, coordinates).unwrap();
+
+        assert_eq!(solar_time.declination().degrees, -7.7850685152648795);
+        assert_eq!(solar_time.right_ascension().degrees, 198.38082214251881);
+        assert_eq!(solar_time.sidereal_time().degrees, 21.80542426334863);
     }
 
     #[test]
@@ -281,27 +451,59 @@ mod tests {
     fn calculate_solar_time() {
         let coordinates = Coordinates::new(35.0 + 47.0 / 60.0, -78.0 - 39.0 / 60.0);
         let date = Utc.ymd(2015, 7, 12).and_hms(0, 0, 0);
-        let solar = SolarTime::new(date, coordinates);
+        let solar = SolarTime::new(date, coordinates).unwrap();
         let transit_date = Utc.ymd(2015, 07, 12).and_hms(17, 20, 0);
         let sunrise_date = Utc.ymd(2015, 07, 12).and_hms(10, 08, 0);
         let sunset_date = Utc.ymd(2015, 07, 13).and_hms(00, 32, 0);
 
-        assert_eq!(solar.transit, transit_date);
-        assert_eq!(solar.sunrise, sunrise_date);
-        assert_eq!(solar.sunset, sunset_date);
+        assert_eq!(
+            solar.transit.rounded_minute(Rounding::Nearest),
+            transit_date
+        );
+        assert_eq!(
+            solar.sunrise.rounded_minute(Rounding::Nearest),
+            sunrise_date
+        );
+        assert_eq!(solar.sunset.rounded_minute(Rounding::Nearest), sunset_date);
+    }
+
+    #[test]
+    fn calculate_solar_time_to_the_second() {
+        // The reference sunrise, 10:07:54.5 UTC, is the time the sun reaches
+        // -0.8333° using the solar position algorithms of Astronomical
+        // Algorithms (chapters 12, 22, and 25) without interpolation.
+        let coordinates = Coordinates::new(35.0 + 47.0 / 60.0, -78.0 - 39.0 / 60.0);
+        let date = Utc.ymd(2015, 7, 12).and_hms(0, 0, 0);
+        let solar = SolarTime::new(date, coordinates).unwrap();
+        let reference = Utc.ymd(2015, 7, 12).and_hms_milli(10, 7, 54, 500);
+
+        assert!((solar.sunrise - reference).num_milliseconds().abs() <= 2000);
+        assert_eq!(solar.sunrise.rounded_minute(Rounding::None), solar.sunrise);
     }
 
     #[test]
     fn calculate_time_for_solar_angle() {
         let coordinates = Coordinates::new(35.0 + 47.0 / 60.0, -78.0 - 39.0 / 60.0);
         let date = Utc.ymd(2015, 7, 12).and_hms(0, 0, 0);
-        let solar = SolarTime::new(date, coordinates);
+        let solar = SolarTime::new(date, coordinates).unwrap();
         let angle = Angle::new(-6.0);
-        let twilight_start = solar.time_for_solar_angle(angle, false);
-        let twilight_end = solar.time_for_solar_angle(angle, true);
-
-        assert_eq!(twilight_start.format("%-k:%M").to_string(), "9:38");
-        assert_eq!(twilight_end.format("%-k:%M").to_string(), "1:02");
+        let twilight_start = solar.time_for_solar_angle(angle, false).unwrap();
+        let twilight_end = solar.time_for_solar_angle(angle, true).unwrap();
+
+        assert_eq!(
+            twilight_start
+                .rounded_minute(Rounding::Nearest)
+                .format("%-k:%M")
+                .to_string(),
+            "9:38"
+        );
+        assert_eq!(
+            twilight_end
+                .rounded_minute(Rounding::Nearest)
+                .format("%-k:%M")
+                .to_string(),
+            "1:02"
+        );
     }
 
     #[test]
@@ -316,7 +518,8 @@ mod tests {
         let prev_solar = SolarCoordinates::new(yesterday.julian_day());
         let solar = SolarCoordinates::new(today.julian_day());
         let next_solar = SolarCoordinates::new(tomorrow.julian_day());
-        let solar_altitude = Angle::new(-50.0 / 60.0);
+        let solar_altitude = Angle::SUN_HORIZON_ALTITUDE;
+
         let approx_transit = ops::approximate_transit(
             coordinates.longitude_angle(),
             solar.apparent_sidereal_time,
@@ -338,4 +541,140 @@ mod tests {
 
         assert_eq!(sunrise_time, 10.131800480632849);
     }
+
+    #[test]
+    fn clock_noon_offset_east_and_west_of_the_meridian() {
+        // The equation of time is close to zero in mid June.
+        let date = Utc.ymd(2021, 6, 13).and_hms(0, 0, 0);
+        let west = SolarTime::new(date, Coordinates::new(40.0, -7.0)).unwrap();
+        let east = SolarTime::new(date, Coordinates::new(40.0, 7.0)).unwrap();
+        let west_offset = west.clock_noon_offset();
+        let east_offset = east.clock_noon_offset();
+        let west_transit = west
+            .transit
+            .signed_duration_since(Utc.ymd(2021, 6, 13).and_hms(12, 0, 0));
+
+        assert!(west_offset > Duration::minutes(27) && west_offset < Duration::minutes(29));
+        assert!(east_offset < Duration::minutes(-27) && east_offset > Duration::minutes(-29));
+        assert!((west_transit - west_offset).num_seconds().abs() <= 60);
+    }
+
+    #[test]
+    fn calculate_time_at_positive_altitude() {
+        let coordinates = Coordinates::new(35.0 + 47.0 / 60.0, -78.0 - 39.0 / 60.0);
+        let date = Utc.ymd(2015, 7, 12).and_hms(0, 0, 0);
+        let solar = SolarTime::new(date, coordinates).unwrap();
+        let morning = solar
+            .time_at_altitude(Angle::new(10.0), true)
+            .unwrap()
+            .rounded_minute(Rounding::Nearest);
+        let afternoon = solar
+            .time_at_altitude(Angle::new(10.0), false)
+            .unwrap()
+            .rounded_minute(Rounding::Nearest);
+
+        // Reference times from the NOAA approximate solar position
+        // equations are 11:06 and 23:34 UTC.
+        assert_eq!(morning.format("%-k:%M").to_string(), "11:06");
+        assert_eq!(afternoon.format("%-k:%M").to_string(), "23:34");
+        assert_eq!(solar.time_at_altitude(Angle::new(80.0), true), None);
+    }
+
+    #[test]
+    fn solar_angle_not_reached() {
+        let date = Utc.ymd(2021, 6, 21).and_hms(0, 0, 0);
+        let solar = SolarTime::new(date, Coordinates::new(60.0, 10.75)).unwrap();
+
+        assert_eq!(solar.time_for_solar_angle(Angle::new(-18.0), false), None);
+        assert_eq!(solar.time_for_solar_angle(Angle::new(-18.0), true), None);
+        assert!(solar.time_for_solar_angle(Angle::new(-4.0), true).is_some());
+    }
+
+    #[test]
+    fn solar_time_without_sunset() {
+        let date = Utc.ymd(2021, 6, 21).and_hms(0, 0, 0);
+        let svalbard = Coordinates::new(80.0, 16.0);
+
+        assert_eq!(
+            SolarTime::new(date, svalbard).unwrap_err(),
+            SolarError::MidnightSun
+        );
+    }
+
+    #[test]
+    fn summary_of_solar_time() {
+        let date = Utc.ymd(2015, 7, 12).and_hms(0, 0, 0);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let solar = SolarTime::new(date, coordinates).unwrap();
+        let summary = solar.summary_string();
+        let lines: Vec<&str> = summary.lines().collect();
+
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            lines[0],
+            format!("Sunrise: {}", solar.sunrise.format("%H:%M:%S"))
+        );
+        assert!(lines[1].starts_with("Solar noon: 17:"));
+        assert!(lines[2].starts_with("Sunset: 00:"));
+        assert!(lines[3].starts_with("Day length: 14:"));
+    }
+
+    #[test]
+    fn solar_time_at_the_poles() {
+        let summer = Utc.ymd(2021, 6, 21).and_hms(0, 0, 0);
+        let winter = Utc.ymd(2021, 12, 21).and_hms(0, 0, 0);
+        let north_pole = Coordinates::new(90.0, 0.0);
+        let south_pole = Coordinates::new(-90.0, 0.0);
+
+        assert_eq!(
+            SolarTime::new(summer, north_pole).unwrap_err(),
+            SolarError::MidnightSun
+        );
+        assert_eq!(
+            SolarTime::new(winter, north_pole).unwrap_err(),
+            SolarError::PolarNight
+        );
+        assert_eq!(
+            SolarTime::new(summer, south_pole).unwrap_err(),
+            SolarError::PolarNight
+        );
+        assert_eq!(
+            SolarTime::new(winter, south_pole).unwrap_err(),
+            SolarError::MidnightSun
+        );
+    }
+
+    #[test]
+    fn solar_time_without_sunrise() {
+        let date = Utc.ymd(2021, 12, 21).and_hms(0, 0, 0);
+        let svalbard = Coordinates::new(80.0, 16.0);
+
+        assert_eq!(
+            SolarTime::new(date, svalbard).unwrap_err(),
+            SolarError::PolarNight
+        );
+    }
+
+    #[test]
+    fn calculate_twilight_status() {
+        let date = Utc.ymd(2015, 7, 12).and_hms(0, 0, 0);
+        let solar = SolarTime::new(date, Coordinates::new(35.7750, -78.6336)).unwrap();
+
+        assert_eq!(
+            solar.twilight_status(Angle::new(18.0)),
+            TwilightStatus::Occurs
+        );
+        assert_eq!(
+            solar.twilight_status(Angle::new(0.0)),
+            TwilightStatus::Occurs
+        );
+
+        let date = Utc.ymd(2021, 6, 21).and_hms(0, 0, 0);
+        let solar = SolarTime::new(date, Coordinates::new(55.0, -1.6)).unwrap();
+
+        assert_eq!(
+            solar.twilight_status(Angle::new(18.0)),
+            TwilightStatus::PersistentTwilight
+        );
+    }
 }
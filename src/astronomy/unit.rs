@@ -6,10 +6,53 @@
 
 use std::f64::consts::PI;
 use std::ops::{Add, Div, Mul, Sub};
+use std::str::FromStr;
 
 use crate::astronomy::ops;
 use crate::models::rounding::Rounding;
-use chrono::{DateTime, Datelike, Duration, TimeZone, Timelike};
+use chrono::{DateTime, Datelike, Duration, TimeZone, Timelike, Utc};
+
+/// The largest adjustment, in minutes, that can be applied
+/// to a time (one day). Adjustments beyond this value, in
+/// either direction, are saturated to it.
+pub const MAX_ADJUSTMENT_MINUTES: i64 = 24 * 60;
+
+// The mean radius of the Earth, in kilometers.
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+// How close, in radians, the angular distance between two points
+// must be to π for them to be treated as antipodal.
+const ANTIPODAL_EPSILON: f64 = 1e-9;
+
+/// A number of minutes, used for adjusting times.
+///
+/// Being a distinct type keeps minutes from being mixed up
+/// with seconds or plain numbers:
+///
+/// ```compile_fail
+/// use salah::prelude::*;
+///
+/// let time = Utc.ymd(2015, 7, 13).and_hms(4, 37, 30);
+/// let adjusted = time.adjust_time(Minutes(Duration::seconds(90)));
+/// ```
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Minutes(pub i64);
+
+impl Minutes {
+    /// The same minutes, saturated to ±`MAX_ADJUSTMENT_MINUTES`.
+    pub fn clamped(self) -> Minutes {
+        Minutes(
+            self.0
+                .clamp(-MAX_ADJUSTMENT_MINUTES, MAX_ADJUSTMENT_MINUTES),
+        )
+    }
+}
+
+impl From<Minutes> for Duration {
+    fn from(minutes: Minutes) -> Duration {
+        Duration::minutes(minutes.0)
+    }
+}
 
 pub trait Normalize {
     fn normalized_to_scale(&self, max: f64) -> f64;
@@ -26,7 +69,7 @@ pub trait Stride {
     fn tomorrow(&self) -> Self;
     fn yesterday(&self) -> Self;
     fn julian_day(&self) -> f64;
-    fn adjust_time(&self, minutes: i64) -> Self;
+    fn adjust_time(&self, minutes: Minutes) -> Self;
     fn next_date(&self, fwd: bool) -> Self;
 	fn rounded_minute(&self, rounding: Rounding) -> Self;
 }
@@ -72,41 +115,67 @@ impl<Tz: TimeZone> Stride for DateTime<Tz> {
 				
 				adjusted + Duration::seconds(60 - adjusted_seconds)
 			},
+			Rounding::Down => {
+				let adjusted_seconds = seconds as i64;
+
+				adjusted + Duration::seconds(adjusted_seconds * -1)
+			},
 			Rounding::None => adjusted,
+			Rounding::ToSeconds(0) => adjusted,
+			Rounding::ToSeconds(step) => {
+				let step = step as i64;
+				let remainder = adjusted.num_seconds_from_midnight() as i64 % step;
+
+				if remainder * 2 >= step {
+					adjusted + Duration::seconds(step - remainder)
+				} else {
+					adjusted + Duration::seconds(-remainder)
+				}
+			},
+			Rounding::Custom(round) => {
+				let timezone = adjusted.timezone();
+
+				round(adjusted.with_timezone(&Utc)).with_timezone(&timezone)
+			},
 		}
 	}
 
-    fn adjust_time(&self, minutes: i64) -> Self {
+    /// Returns the date/time adjusted by the given minutes. The
+    /// adjustment is clamped to ±`MAX_ADJUSTMENT_MINUTES`.
+    fn adjust_time(&self, minutes: Minutes) -> Self {
         let some_date = self.clone();
+
         some_date
-            .checked_add_signed(Duration::seconds(minutes * 60))
+            .checked_add_signed(minutes.clamped().into())
             .unwrap()
     }
 
     fn next_date(&self, fwd: bool) -> Self {
-        let ordinal = if fwd {
-            self.ordinal() + 1
+        let is_first_day = self.month() == 1 && self.day() == 1;
+        let is_last_day = self.month() == 12 && self.day() == 31;
+
+        if fwd && is_last_day {
+            // December 31 rolls over to January 1 of the next year.
+            self.with_year(self.year() + 1)
+                .unwrap()
+                .with_ordinal(1)
+                .unwrap()
+        } else if !fwd && is_first_day {
+            // January 1 rolls back to December 31 of the previous year.
+            self.with_year(self.year() - 1)
+                .unwrap()
+                .with_month(12)
+                .unwrap()
+                .with_day(31)
+                .unwrap()
         } else {
-            self.ordinal() - 1
-        };
-
-        match self.with_ordinal(ordinal) {
-            Some(dt) => dt,
-            None => {
-                if fwd {
-                    self.with_year(self.year() + 1)
-                        .unwrap()
-                        .with_ordinal(1)
-                        .unwrap()
-                } else {
-                    self.with_year(self.year() - 1)
-                        .unwrap()
-                        .with_month(12)
-                        .unwrap()
-                        .with_day(31)
-                        .unwrap()
-                }
-            }
+            let ordinal = if fwd {
+                self.ordinal() + 1
+            } else {
+                self.ordinal() - 1
+            };
+
+            self.with_ordinal(ordinal).unwrap()
         }
     }
 }
@@ -117,10 +186,33 @@ pub struct Angle {
 }
 
 impl Angle {
+    /// The altitude of the center of the sun at sunrise and sunset,
+    /// accounting for atmospheric refraction and the radius of the sun.
+    pub const SUN_HORIZON_ALTITUDE: Angle = Angle {
+        degrees: -50.0 / 60.0,
+    };
+
     pub fn new(value: f64) -> Self {
         Angle { degrees: value }
     }
 
+    /// Creates an angle from a value in minutes of arc.
+    pub fn arcminutes(value: f64) -> Self {
+        Angle {
+            degrees: value / 60.0,
+        }
+    }
+
+    /// Creates an angle from degrees, minutes, and seconds of arc.
+    /// The sign of the degrees applies to the whole angle.
+    pub fn dms(degrees: f64, minutes: f64, seconds: f64) -> Self {
+        let magnitude = degrees.abs() + (minutes / 60.0) + (seconds / 3600.0);
+
+        Angle {
+            degrees: magnitude.copysign(degrees),
+        }
+    }
+
     pub fn from_radians(value: f64) -> Self {
         Angle {
             degrees: (value * 180.0) / PI,
@@ -151,6 +243,13 @@ impl Angle {
 
         angle
     }
+
+    /// The smallest signed difference, in the range −180° to 180°,
+    /// needed to turn from this bearing to the other one. Positive
+    /// values are clockwise (turning right).
+    pub fn shortest_difference(self, other: Angle) -> Angle {
+        (other - self).quadrant_shifted()
+    }
 }
 
 impl Add for Angle {
@@ -212,6 +311,78 @@ impl Coordinates {
             longitude: longitude,
         }
     }
+
+    /// The coordinates of the Kaaba in Makkah.
+    pub fn makkah() -> Self {
+        Coordinates::new(21.4225241, 39.8261818)
+    }
+
+    /// The point on the opposite side of the Earth.
+    pub fn antipode(&self) -> Coordinates {
+        Coordinates::new(
+            -self.latitude,
+            Angle::new(self.longitude + 180.0)
+                .quadrant_shifted()
+                .degrees,
+        )
+    }
+
+    /// Returns whether the coordinates are in the northern
+    /// hemisphere. Points on the equator count as northern.
+    pub fn is_northern(&self) -> bool {
+        self.latitude >= 0.0
+    }
+
+    /// The great circle distance, in kilometers, to the other
+    /// coordinates, assuming a spherical Earth.
+    pub fn distance_to(&self, other: &Coordinates) -> f64 {
+        self.angular_distance(other) * EARTH_RADIUS_KM
+    }
+
+    // The angle, in radians, between the two points
+    // at the center of the Earth (haversine formula).
+    fn angular_distance(&self, other: &Coordinates) -> f64 {
+        let latitude_1 = self.latitude_angle().radians();
+        let longitude_1 = self.longitude_angle().radians();
+        let latitude_2 = other.latitude_angle().radians();
+        let longitude_2 = other.longitude_angle().radians();
+        let term1 = ((latitude_2 - latitude_1) / 2.0).sin().powi(2);
+        let term2 =
+            latitude_1.cos() * latitude_2.cos() * ((longitude_2 - longitude_1) / 2.0).sin().powi(2);
+
+        2.0 * (term1 + term2).sqrt().asin()
+    }
+
+    /// The point at the given fraction (`0.0` to `1.0`) of the great
+    /// circle path from these coordinates to the other coordinates.
+    /// Returns `None` when the coordinates are antipodal, since every
+    /// great circle through them is equally short.
+    pub fn interpolate(&self, other: &Coordinates, fraction: f64) -> Option<Coordinates> {
+        let latitude_1 = self.latitude_angle().radians();
+        let longitude_1 = self.longitude_angle().radians();
+        let latitude_2 = other.latitude_angle().radians();
+        let longitude_2 = other.longitude_angle().radians();
+        let distance = self.angular_distance(other);
+
+        if distance == 0.0 {
+            Some(*self)
+        } else if PI - distance < ANTIPODAL_EPSILON {
+            None
+        } else {
+            let a = ((1.0 - fraction) * distance).sin() / distance.sin();
+            let b = (fraction * distance).sin() / distance.sin();
+            let x =
+                a * latitude_1.cos() * longitude_1.cos() + b * latitude_2.cos() * longitude_2.cos();
+            let y =
+                a * latitude_1.cos() * longitude_1.sin() + b * latitude_2.cos() * longitude_2.sin();
+            let z = a * latitude_1.sin() + b * latitude_2.sin();
+
+            Some(Coordinates::new(
+                Angle::from_radians(z.atan2((x * x + y * y).sqrt())).degrees,
+                Angle::from_radians(y.atan2(x)).degrees,
+            ))
+        }
+    }
 }
 
 impl Coordinates {
@@ -224,6 +395,54 @@ impl Coordinates {
     }
 }
 
+impl FromStr for Coordinates {
+    type Err = String;
+
+    /// Parses coordinates written as `"lat,lon"` (e.g. `"40.7128, -74.0059"`)
+    /// or as a geo URI (e.g. `"geo:40.7128,-74.0059"`). The altitude and the
+    /// parameters of a geo URI are ignored.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        let value = value.strip_prefix("geo:").unwrap_or(value);
+        let value = value.split(';').next().unwrap_or(value);
+        let parts: Vec<&str> = value.split(',').map(|part| part.trim()).collect();
+
+        if parts.len() < 2 || parts.len() > 3 {
+            return Err(format!(
+                "Expected \"lat,lon\" coordinates, got \"{}\".",
+                value
+            ));
+        } else {
+            // Nothing to do.
+        }
+
+        let latitude = parts[0]
+            .parse::<f64>()
+            .map_err(|_| format!("Invalid latitude \"{}\".", parts[0]))?;
+        let longitude = parts[1]
+            .parse::<f64>()
+            .map_err(|_| format!("Invalid longitude \"{}\".", parts[1]))?;
+
+        if !(-90.0..=90.0).contains(&latitude) {
+            Err(format!("Latitude {} is out of range.", latitude))
+        } else if !(-180.0..=180.0).contains(&longitude) {
+            Err(format!("Longitude {} is out of range.", longitude))
+        } else {
+            Ok(Coordinates::new(latitude, longitude))
+        }
+    }
+}
+
+/// Whether the current location is further than `threshold_km`
+/// (e.g. 88 km) from the home location, for the shortening and
+/// combining of prayers while traveling.
+///
+/// This is only a convenience based on the great circle distance;
+/// the conditions of travel (*safar*) are a matter of fiqh.
+pub fn is_traveler(home: &Coordinates, current: &Coordinates, threshold_km: f64) -> bool {
+    home.distance_to(current) > threshold_km
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -288,6 +507,192 @@ mod tests {
         assert_eq!((angle_a + angle_b).degrees, 90.0);
     }
 	
+    #[test]
+    fn shortest_difference_between_angles() {
+        assert_eq!(
+            Angle::new(350.0)
+                .shortest_difference(Angle::new(10.0))
+                .degrees,
+            20.0
+        );
+        assert_eq!(
+            Angle::new(10.0)
+                .shortest_difference(Angle::new(350.0))
+                .degrees,
+            -20.0
+        );
+        assert_eq!(
+            Angle::new(90.0)
+                .shortest_difference(Angle::new(45.0))
+                .degrees,
+            -45.0
+        );
+    }
+
+    #[test]
+    fn angle_from_arcminutes() {
+        assert_eq!(Angle::arcminutes(30.0).degrees, 0.5);
+        assert_eq!(
+            Angle::arcminutes(-50.0).degrees,
+            Angle::SUN_HORIZON_ALTITUDE.degrees
+        );
+    }
+
+    #[test]
+    fn angle_from_degrees_minutes_seconds() {
+        assert_eq!(Angle::dms(21.0, 25.0, 21.0).degrees, 21.4225);
+        assert_eq!(Angle::dms(-78.0, 30.0, 0.0).degrees, -78.5);
+    }
+
+    #[test]
+    fn interpolate_coordinates_along_the_equator() {
+        let start = Coordinates::new(0.0, 10.0);
+        let end = Coordinates::new(0.0, 30.0);
+        let halfway = start.interpolate(&end, 0.5).unwrap();
+
+        assert!(halfway.latitude.abs() < 1e-9);
+        assert!((halfway.longitude - 20.0).abs() < 1e-9);
+        assert_eq!(start.interpolate(&start, 0.5), Some(start));
+    }
+
+    #[test]
+    fn interpolate_antipodal_coordinates() {
+        let start = Coordinates::new(0.0, 10.0);
+        let end = Coordinates::new(0.0, -170.0);
+        let north_pole = Coordinates::new(90.0, 0.0);
+        let south_pole = Coordinates::new(-90.0, 0.0);
+
+        assert_eq!(start.interpolate(&end, 0.5), None);
+        assert_eq!(north_pole.interpolate(&south_pole, 0.25), None);
+    }
+
+    #[test]
+    fn antipode_of_coordinates() {
+        let new_york = Coordinates::new(40.0, -74.0);
+        let antipode = new_york.antipode();
+
+        assert_eq!(antipode, Coordinates::new(-40.0, 106.0));
+        assert_eq!(antipode.antipode(), new_york);
+        assert_eq!(Coordinates::new(10.0, 170.0).antipode().longitude, -10.0);
+    }
+
+    #[test]
+    fn hemisphere_of_coordinates() {
+        assert!(Coordinates::new(40.0, -74.0).is_northern());
+        assert!(!Coordinates::new(-33.8688, 151.2093).is_northern());
+        assert!(Coordinates::new(0.0, 10.0).is_northern());
+    }
+
+    #[test]
+    fn distance_between_coordinates() {
+        let new_york = Coordinates::new(40.7128, -74.0059);
+        let london = Coordinates::new(51.5074, -0.1278);
+
+        assert!((new_york.distance_to(&london) - 5570.2).abs() < 1.0);
+        assert_eq!(new_york.distance_to(&new_york), 0.0);
+    }
+
+    #[test]
+    fn parse_coordinates_from_string() {
+        let new_york = Coordinates::new(40.7128, -74.0059);
+
+        assert_eq!("40.7128,-74.0059".parse::<Coordinates>(), Ok(new_york));
+        assert_eq!(" 40.7128 , -74.0059 ".parse::<Coordinates>(), Ok(new_york));
+        assert_eq!("geo:40.7128,-74.0059".parse::<Coordinates>(), Ok(new_york));
+        assert_eq!(
+            "geo:40.7128,-74.0059,10;u=35".parse::<Coordinates>(),
+            Ok(new_york)
+        );
+        assert!("40.7128".parse::<Coordinates>().is_err());
+        assert!("north,west".parse::<Coordinates>().is_err());
+        assert!("91.0,0.0".parse::<Coordinates>().is_err());
+        assert!("NaN,0.0".parse::<Coordinates>().is_err());
+    }
+
+    #[test]
+    fn traveler_beyond_the_threshold() {
+        let makkah = Coordinates::makkah();
+        let jeddah = Coordinates::new(21.4858, 39.1925);
+        let madinah = Coordinates::new(24.4672, 39.6112);
+
+        assert!(!is_traveler(&makkah, &jeddah, 88.0));
+        assert!(is_traveler(&makkah, &madinah, 88.0));
+        assert!(!is_traveler(&makkah, &makkah, 0.0));
+    }
+
+    #[test]
+    fn makkah_coordinates() {
+        let makkah = Coordinates::makkah();
+
+        assert_eq!(makkah.latitude, 21.4225241);
+        assert_eq!(makkah.longitude, 39.8261818);
+    }
+
+    #[test]
+    fn convert_minutes_to_duration() {
+        assert_eq!(Duration::from(Minutes(90)), Duration::seconds(5400));
+        assert_eq!(Duration::from(Minutes(-3)), Duration::seconds(-180));
+    }
+
+    #[test]
+    fn adjust_time_with_huge_adjustment() {
+        let time = Utc.ymd(2015, 7, 13).and_hms(4, 37, 30);
+
+        assert_eq!(
+            time.adjust_time(Minutes(i64::MAX)),
+            Utc.ymd(2015, 7, 14).and_hms(4, 37, 30)
+        );
+        assert_eq!(
+            time.adjust_time(Minutes(i64::MIN)),
+            Utc.ymd(2015, 7, 12).and_hms(4, 37, 30)
+        );
+        assert_eq!(
+            time.adjust_time(Minutes(-90)),
+            Utc.ymd(2015, 7, 13).and_hms(3, 7, 30)
+        );
+    }
+
+    #[test]
+    fn yesterday_on_the_first_day_of_the_year() {
+        let new_year = Utc.ymd(2021, 1, 1).and_hms(10, 30, 0);
+        let leap_new_year = Utc.ymd(2017, 1, 1).and_hms(0, 0, 0);
+
+        assert_eq!(
+            new_year.yesterday(),
+            Utc.ymd(2020, 12, 31).and_hms(10, 30, 0)
+        );
+        assert_eq!(
+            leap_new_year.yesterday(),
+            Utc.ymd(2016, 12, 31).and_hms(0, 0, 0)
+        );
+        assert_eq!(
+            Utc.ymd(2016, 3, 1).and_hms(0, 0, 0).yesterday(),
+            Utc.ymd(2016, 2, 29).and_hms(0, 0, 0)
+        );
+        assert_eq!(
+            Utc.ymd(2015, 3, 1).and_hms(0, 0, 0).yesterday(),
+            Utc.ymd(2015, 2, 28).and_hms(0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn tomorrow_on_the_last_day_of_the_year() {
+        let new_years_eve = Utc.ymd(2020, 12, 31).and_hms(10, 30, 0);
+
+        assert_eq!(
+            new_years_eve.tomorrow(),
+            Utc.ymd(2021, 1, 1).and_hms(10, 30, 0)
+        );
+        assert_eq!(
+            Utc.ymd(2021, 12, 31).and_hms(0, 0, 0).tomorrow(),
+            Utc.ymd(2022, 1, 1).and_hms(0, 0, 0)
+        );
+        assert_eq!(
+            Utc.ymd(2016, 2, 28).and_hms(0, 0, 0).tomorrow(),
+            Utc.ymd(2016, 2, 29).and_hms(0, 0, 0)
+        );
+    }
+
 	#[test]
 	fn calculate_rounding_nearest() {
         let time_1 = Utc.ymd(2015, 7, 13).and_hms(4, 37, 30);
@@ -302,6 +707,27 @@ mod tests {
 		assert_eq!(time_1.rounded_minute(Rounding::Up), Utc.ymd(2015, 7, 13).and_hms(6, 00, 00));
 	}
 	
+	#[test]
+	fn calculate_rounding_down() {
+		let time_1 = Utc.ymd(2015, 7, 13).and_hms(4, 37, 50);
+
+		assert_eq!(time_1.rounded_minute(Rounding::Down), Utc.ymd(2015, 7, 13).and_hms(4, 37, 0));
+		assert_eq!(time_1.rounded_minute(Rounding::Nearest), Utc.ymd(2015, 7, 13).and_hms(4, 38, 0));
+	}
+
+	#[test]
+	fn calculate_rounding_to_seconds() {
+		let time_1 = Utc.ymd(2015, 7, 13).and_hms(4, 37, 22);
+		let time_2 = Utc.ymd(2015, 7, 13).and_hms(4, 59, 50);
+		let time_3 = Utc.ymd(2015, 7, 13).and_hms(23, 59, 55);
+
+		assert_eq!(time_1.rounded_minute(Rounding::ToSeconds(30)), Utc.ymd(2015, 7, 13).and_hms(4, 37, 30));
+		assert_eq!(time_1.rounded_minute(Rounding::ToSeconds(15)), Utc.ymd(2015, 7, 13).and_hms(4, 37, 15));
+		assert_eq!(time_2.rounded_minute(Rounding::ToSeconds(30)), Utc.ymd(2015, 7, 13).and_hms(5, 0, 0));
+		assert_eq!(time_3.rounded_minute(Rounding::ToSeconds(10)), Utc.ymd(2015, 7, 14).and_hms(0, 0, 0));
+		assert_eq!(time_1.rounded_minute(Rounding::ToSeconds(0)), time_1);
+	}
+
 	#[test]
 	fn calculate_rounding_none() {
 		let time_1 = Utc.ymd(2015, 07, 13).and_hms(05, 59, 20);
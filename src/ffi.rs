@@ -0,0 +1,82 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+use chrono::TimeZone;
+
+use crate::models::prayer::Prayer;
+use crate::schedule::PrayerTimes;
+
+/// A flat, C compatible representation of [PrayerTimes](struct.PrayerTimes.html),
+/// for exposing the library over a C ABI (e.g. to Swift or Kotlin).
+///
+/// Each field is the UNIX timestamp, in seconds, of the prayer.
+#[repr(C)]
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct CTimes {
+    pub fajr: i64,
+    pub sunrise: i64,
+    pub dhuhr: i64,
+    pub asr: i64,
+    pub maghrib: i64,
+    pub isha: i64,
+    pub qiyam: i64,
+    pub fajr_tomorrow: i64,
+}
+
+impl<Tz: TimeZone> PrayerTimes<Tz> {
+    /// Returns the times as UNIX timestamps in a `#[repr(C)]` struct.
+    pub fn to_c(&self) -> CTimes {
+        CTimes {
+            fajr: self.time(Prayer::Fajr).timestamp(),
+            sunrise: self.time(Prayer::Sunrise).timestamp(),
+            dhuhr: self.time(Prayer::Dhuhr).timestamp(),
+            asr: self.time(Prayer::Asr).timestamp(),
+            maghrib: self.time(Prayer::Maghrib).timestamp(),
+            isha: self.time(Prayer::Isha).timestamp(),
+            qiyam: self.time(Prayer::Qiyam).timestamp(),
+            fajr_tomorrow: self.time(Prayer::FajrTomorrow).timestamp(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::astronomy::unit::Coordinates;
+    use crate::models::madhab::Madhab;
+    use crate::models::method::Method;
+    use crate::models::parameters::Configuration;
+    use chrono::{FixedOffset, Utc};
+
+    #[test]
+    fn convert_times_to_c_timestamps() {
+        let date = Utc.ymd(2015, 7, 12);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let times = PrayerTimes::new(date, coordinates, params);
+        let c_times = times.to_c();
+
+        assert_eq!(c_times.fajr, times.time(Prayer::Fajr).timestamp());
+        assert_eq!(c_times.sunrise, times.time(Prayer::Sunrise).timestamp());
+        assert_eq!(c_times.dhuhr, times.time(Prayer::Dhuhr).timestamp());
+        assert_eq!(c_times.asr, times.time(Prayer::Asr).timestamp());
+        assert_eq!(c_times.maghrib, times.time(Prayer::Maghrib).timestamp());
+        assert_eq!(c_times.isha, times.time(Prayer::Isha).timestamp());
+        assert_eq!(c_times.qiyam, times.time(Prayer::Qiyam).timestamp());
+        assert_eq!(
+            c_times.fajr_tomorrow,
+            times.time(Prayer::FajrTomorrow).timestamp()
+        );
+        assert_eq!(
+            c_times.dhuhr,
+            Utc.ymd(2015, 7, 12).and_hms(17, 21, 0).timestamp()
+        );
+        assert_eq!(
+            times.with_timezone(&FixedOffset::west(4 * 3600)).to_c(),
+            c_times
+        );
+    }
+}
@@ -24,45 +24,100 @@
 //! ```
 
 mod astronomy;
+#[cfg(feature = "ffi")]
+mod ffi;
 mod models;
 mod schedule;
 
-pub use crate::astronomy::unit::{Coordinates, Stride};
+pub use crate::astronomy::ops::{equation_of_time, solar_declination};
+pub use crate::astronomy::solar::{SolarError, SolarTime};
+pub use crate::astronomy::unit::{is_traveler, Angle, Coordinates, Minutes, Stride};
+#[cfg(feature = "ffi")]
+pub use crate::ffi::CTimes;
 pub use crate::models::adjustments::{Adjustment, TimeAdjustment};
+pub use crate::models::calendar::Calendar;
+pub use crate::models::hijri::Hijri;
+pub use crate::models::high_altitude_rule::HighLatitudeRule;
+pub use crate::models::isha_fixed_time::IshaFixedTime;
 pub use crate::models::madhab::Madhab;
-pub use crate::models::method::Method;
+pub use crate::models::method::{CalculationMethod, Method};
+pub use crate::models::night_definition::NightDefinition;
 pub use crate::models::parameters::{Configuration, Parameters};
 pub use crate::models::prayer::Prayer;
-pub use crate::schedule::{PrayerSchedule, PrayerTimes};
-pub use chrono::{Date, DateTime, Datelike, Duration, Local, TimeZone, Timelike, Utc};
+pub use crate::models::qiyam_base::QiyamBase;
+pub use crate::models::reason::Reason;
+pub use crate::models::rounding::{Rounding, RoundingStrategy};
+pub use crate::models::shafaq::Shafaq;
+pub use crate::models::solar_day_basis::SolarDayBasis;
+pub use crate::models::twilight_status::TwilightStatus;
+pub use crate::schedule::{
+    month_json, times_from_timestamp, NaiveTimes, PrayerClock, PrayerSchedule, PrayerTimes,
+    WeeklyTimetable,
+};
+pub use chrono::{
+    Date, DateTime, Datelike, Duration, FixedOffset, Local, NaiveDate, NaiveDateTime, TimeZone,
+    Timelike, Utc,
+};
 
 /// A convenience module appropriate for glob imports (`use salah::prelude::*;`).
 pub mod prelude {
     #[doc(no_inline)]
-    pub use crate::astronomy::qiblah::Qiblah;
+    pub use crate::astronomy::qiblah::{Qiblah, QiblahInfo};
     #[doc(no_inline)]
-    pub use crate::astronomy::unit::{Coordinates, Stride};
+    pub use crate::astronomy::solar::{SolarError, SolarTime};
+    #[doc(no_inline)]
+    pub use crate::astronomy::unit::{is_traveler, Angle, Coordinates, Minutes, Stride};
+    #[cfg(feature = "ffi")]
+    #[doc(no_inline)]
+    pub use crate::ffi::CTimes;
     #[doc(no_inline)]
     pub use crate::models::adjustments::{Adjustment, TimeAdjustment};
     #[doc(no_inline)]
+    pub use crate::models::calendar::Calendar;
+    #[doc(no_inline)]
+    pub use crate::models::hijri::Hijri;
+    #[doc(no_inline)]
+    pub use crate::models::high_altitude_rule::HighLatitudeRule;
+    #[doc(no_inline)]
+    pub use crate::models::isha_fixed_time::IshaFixedTime;
+    #[doc(no_inline)]
     pub use crate::models::madhab::Madhab;
     #[doc(no_inline)]
-    pub use crate::models::method::Method;
+    pub use crate::models::method::{CalculationMethod, Method};
+    #[doc(no_inline)]
+    pub use crate::models::night_definition::NightDefinition;
     #[doc(no_inline)]
     pub use crate::models::parameters::{Configuration, Parameters};
     #[doc(no_inline)]
     pub use crate::models::prayer::Prayer;
     #[doc(no_inline)]
-    pub use crate::schedule::{PrayerSchedule, PrayerTimes};
+    pub use crate::models::qiyam_base::QiyamBase;
+    #[doc(no_inline)]
+    pub use crate::models::reason::Reason;
+    #[doc(no_inline)]
+    pub use crate::models::rounding::{Rounding, RoundingStrategy};
+    #[doc(no_inline)]
+    pub use crate::models::shafaq::Shafaq;
+    #[doc(no_inline)]
+    pub use crate::models::solar_day_basis::SolarDayBasis;
+    #[doc(no_inline)]
+    pub use crate::models::twilight_status::TwilightStatus;
+    #[doc(no_inline)]
+    pub use crate::schedule::{
+        month_json, times_from_timestamp, NaiveTimes, PrayerClock, PrayerSchedule, PrayerTimes,
+        WeeklyTimetable,
+    };
     #[doc(no_inline)]
-    pub use chrono::{Date, DateTime, Datelike, Duration, Local, TimeZone, Timelike, Utc};
+    pub use chrono::{
+        Date, DateTime, Datelike, Duration, FixedOffset, Local, NaiveDate, NaiveDateTime,
+        TimeZone, Timelike, Utc,
+    };
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::models::high_altitude_rule::HighLatitudeRule;
-    use chrono::prelude::*;
 
     #[test]
     fn calculate_prayer_times() {
@@ -204,7 +259,8 @@ mod tests {
     fn calculate_times_for_singapore() {
         let mut params = Configuration::with(Method::Singapore, Madhab::Shafi);
 
-        params.high_latitude_rule = HighLatitudeRule::MiddleOfTheNight;
+        params.fajr_high_latitude_rule = HighLatitudeRule::MiddleOfTheNight;
+        params.isha_high_latitude_rule = HighLatitudeRule::MiddleOfTheNight;
 
         let result = PrayerSchedule::new()
             .on(Utc.ymd(2021, 1, 13))
@@ -224,11 +280,11 @@ mod tests {
                 let sgt_isha = schedule.time(Prayer::Isha).with_timezone(&sgt_offset);
 
                 assert_eq!(sgt_fajr.format("%-l:%M %p").to_string(), "5:50 AM");
-                assert_eq!(sgt_sunrise.format("%-l:%M %p").to_string(), "7:13 AM");
+                assert_eq!(sgt_sunrise.format("%-l:%M %p").to_string(), "7:12 AM");
                 assert_eq!(sgt_dhuhr.format("%-l:%M %p").to_string(), "1:15 PM");
                 assert_eq!(sgt_asr.format("%-l:%M %p").to_string(), "4:39 PM");
-                assert_eq!(sgt_maghrib.format("%-l:%M %p").to_string(), "7:16 PM");
-                assert_eq!(sgt_isha.format("%-l:%M %p").to_string(), "8:30 PM");
+                assert_eq!(sgt_maghrib.format("%-l:%M %p").to_string(), "7:15 PM");
+                assert_eq!(sgt_isha.format("%-l:%M %p").to_string(), "8:29 PM");
             }
             Err(_err) => assert!(false),
         }
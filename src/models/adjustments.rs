@@ -30,6 +30,24 @@ impl TimeAdjustment {
             isha: isha,
         }
     }
+
+    /// Sums any number of adjustments field by field, so that
+    /// layered offsets (method, municipal, personal) can be folded
+    /// into one. An empty slice yields the default adjustment.
+    pub fn combine_all(adjustments: &[TimeAdjustment]) -> TimeAdjustment {
+        adjustments
+            .iter()
+            .fold(TimeAdjustment::default(), |total, adjustment| {
+                TimeAdjustment {
+                    fajr: total.fajr + adjustment.fajr,
+                    sunrise: total.sunrise + adjustment.sunrise,
+                    dhuhr: total.dhuhr + adjustment.dhuhr,
+                    asr: total.asr + adjustment.asr,
+                    maghrib: total.maghrib + adjustment.maghrib,
+                    isha: total.isha + adjustment.isha,
+                }
+            })
+    }
 }
 
 impl Default for TimeAdjustment {
@@ -68,6 +86,21 @@ impl Adjustment {
         }
     }
 
+    /// The municipal offsets used in Dubai: sunrise 3 minutes
+    /// earlier, and Dhuhr, Asr, and Maghrib 3 minutes later. These are
+    /// the method adjustments of `Method::Dubai`, and can be applied
+    /// on their own or further modified before calling `done()`.
+    pub fn dubai() -> Adjustment {
+        Adjustment {
+            fajr: 0,
+            sunrise: -3,
+            dhuhr: 3,
+            asr: 3,
+            maghrib: 3,
+            isha: 0,
+        }
+    }
+
     pub fn fajr<'a>(&'a mut self, fajr: i64) -> &'a mut Adjustment {
         self.fajr = fajr;
         self
@@ -109,3 +142,28 @@ impl Adjustment {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combine_three_adjustments() {
+        let method = Adjustment::dubai().done();
+        let municipal = TimeAdjustment::new(2, 0, 1, 0, 0, -2);
+        let personal = Adjustment::new().fajr(-5).isha(10).done();
+        let combined = TimeAdjustment::combine_all(&[method, municipal, personal]);
+
+        assert_eq!(combined.fajr, -3);
+        assert_eq!(combined.sunrise, -3);
+        assert_eq!(combined.dhuhr, 4);
+        assert_eq!(combined.asr, 3);
+        assert_eq!(combined.maghrib, 3);
+        assert_eq!(combined.isha, 8);
+    }
+
+    #[test]
+    fn combine_no_adjustments() {
+        assert_eq!(TimeAdjustment::combine_all(&[]), TimeAdjustment::default());
+    }
+}
@@ -0,0 +1,46 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+use crate::astronomy::ops;
+
+/// The calendar that a date is expressed in.
+///
+/// Dates are normally treated as (proleptic) Gregorian. Historical
+/// dates from before the Gregorian reform of 1582 are usually recorded
+/// in the Julian calendar instead.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum Calendar {
+    Gregorian,
+    Julian,
+}
+
+impl Calendar {
+    /// The Julian day at the start (midnight UTC)
+    /// of the given date in this calendar.
+    pub fn julian_day(&self, year: i32, month: u32, day: u32) -> f64 {
+        ops::julian_day_for_calendar(*self, year, month as i32, day as i32, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn julian_day_for_the_gregorian_reform() {
+        // The day after 4 October 1582 (Julian) was 15 October 1582 (Gregorian).
+        assert_eq!(Calendar::Julian.julian_day(1582, 10, 4), 2299159.5);
+        assert_eq!(Calendar::Gregorian.julian_day(1582, 10, 15), 2299160.5);
+    }
+
+    #[test]
+    fn julian_day_for_both_calendars_in_1500() {
+        let julian = Calendar::Julian.julian_day(1500, 6, 1);
+        let gregorian = Calendar::Gregorian.julian_day(1500, 6, 1);
+
+        assert_eq!(julian - gregorian, 10.0);
+    }
+}
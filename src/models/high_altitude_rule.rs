@@ -26,6 +26,14 @@ pub enum HighLatitudeRule {
     ///
     /// This can be used to prevent difficult fajr and isha times at certain locations.
     TwilightAngle,
+
+    /// Half of the night is split between Fajr and Isha in proportion to their
+    /// angles: Fajr won't be earlier than fajr/(fajr + isha) of half the night
+    /// before sunrise and Isha won't be later than isha/(fajr + isha) of half
+    /// the night after sunset.
+    ///
+    /// This gives smoother times than a fixed portion when the two angles differ.
+    AngleProportional,
 }
 
 impl HighLatitudeRule {
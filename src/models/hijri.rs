@@ -0,0 +1,111 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::astronomy::ops;
+
+// The Julian day of 1 Muharram 1 AH in the
+// civil (Friday epoch) tabular calendar.
+const HIJRI_EPOCH: f64 = 1948439.5;
+
+// The Julian day at the end of 31 December 0 (proleptic
+// Gregorian), the day before the first day of the common era.
+const COMMON_ERA_EPOCH: f64 = 1721424.5;
+
+/// A date in the tabular (arithmetic) Islamic calendar.
+///
+/// The tabular calendar follows a fixed 30 year cycle of leap years
+/// and may differ by a day or two from calendars that are based on
+/// the sighting of the moon, such as Umm al-Qura.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Hijri {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Hijri {
+    /// The month of Ramadan.
+    pub const RAMADAN: u32 = 9;
+
+    pub fn new(year: i32, month: u32, day: u32) -> Self {
+        Hijri {
+            year: year,
+            month: month,
+            day: day,
+        }
+    }
+
+    /// Converts the given Gregorian date to the tabular Islamic calendar.
+    pub fn from_gregorian<D: Datelike>(date: &D) -> Self {
+        let julian_day = ops::julian_day(date.year(), date.month() as i32, date.day() as i32, 0.0);
+        let year = ((30.0 * (julian_day - HIJRI_EPOCH) + 10646.0) / 10631.0).floor() as i32;
+        let first_of_year = Hijri::new(year, 1, 1).julian_day();
+        let month = (((julian_day - (29.0 + first_of_year)) / 29.5).ceil() + 1.0).min(12.0) as u32;
+        let day = (julian_day - Hijri::new(year, month, 1).julian_day()) as u32 + 1;
+
+        Hijri::new(year, month, day)
+    }
+
+    /// The Julian day at the start (midnight) of this date.
+    pub fn julian_day(&self) -> f64 {
+        let year = self.year as f64;
+
+        (self.day as f64)
+            + (29.5 * (self.month as f64 - 1.0)).ceil()
+            + (year - 1.0) * 354.0
+            + ((3.0 + 11.0 * year) / 30.0).floor()
+            + HIJRI_EPOCH
+            - 1.0
+    }
+
+    /// Converts this date to the (proleptic) Gregorian calendar.
+    pub fn to_gregorian(&self) -> NaiveDate {
+        let days = (self.julian_day() - COMMON_ERA_EPOCH).round() as i32;
+
+        NaiveDate::from_num_days_from_ce_opt(days).unwrap()
+    }
+
+    pub fn is_ramadan(&self) -> bool {
+        self.month == Hijri::RAMADAN
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn convert_gregorian_to_hijri() {
+        let start_of_ramadan = NaiveDate::from_ymd(2021, 4, 13);
+        let eid_al_fitr = NaiveDate::from_ymd(2021, 5, 13);
+
+        assert_eq!(
+            Hijri::from_gregorian(&start_of_ramadan),
+            Hijri::new(1442, 9, 1)
+        );
+        assert_eq!(Hijri::from_gregorian(&eid_al_fitr), Hijri::new(1442, 10, 1));
+        assert!(Hijri::from_gregorian(&start_of_ramadan).is_ramadan());
+        assert!(!Hijri::from_gregorian(&eid_al_fitr).is_ramadan());
+    }
+
+    #[test]
+    fn convert_hijri_to_gregorian() {
+        let start_of_ramadan = Hijri::new(1442, 9, 1);
+        let mid_ramadan = Hijri::new(1446, 9, 15);
+
+        assert_eq!(
+            start_of_ramadan.to_gregorian(),
+            NaiveDate::from_ymd(2021, 4, 13)
+        );
+        assert_eq!(
+            Hijri::from_gregorian(&mid_ramadan.to_gregorian()),
+            mid_ramadan
+        );
+    }
+}
@@ -0,0 +1,56 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+use chrono::{DateTime, FixedOffset, NaiveTime, TimeZone, Utc};
+
+/// A fixed wall clock time for Isha, as published by some
+/// mosques at high latitudes (e.g. in Norway) during the summer,
+/// when the astronomical Isha is very late or never occurs.
+///
+/// This is a community convention rather than an astronomical
+/// calculation. The time is local to the given UTC offset.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct IshaFixedTime {
+    pub time: NaiveTime,
+    pub offset: FixedOffset,
+}
+
+impl IshaFixedTime {
+    pub fn new(time: NaiveTime, offset: FixedOffset) -> Self {
+        IshaFixedTime {
+            time: time,
+            offset: offset,
+        }
+    }
+
+    /// The fixed time on the local day of the given sunset.
+    pub fn for_sunset(&self, sunset: DateTime<Utc>) -> DateTime<Utc> {
+        let local_date = sunset.with_timezone(&self.offset).date_naive();
+
+        self.offset
+            .from_local_datetime(&local_date.and_time(self.time))
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_time_on_the_local_day_of_sunset() {
+        let fixed = IshaFixedTime::new(NaiveTime::from_hms(23, 0, 0), FixedOffset::east(2 * 3600));
+
+        // 22:30 local time on June 21st.
+        let sunset = Utc.ymd(2021, 6, 21).and_hms(20, 30, 0);
+
+        assert_eq!(
+            fixed.for_sunset(sunset),
+            Utc.ymd(2021, 6, 21).and_hms(21, 0, 0)
+        );
+    }
+}
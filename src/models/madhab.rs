@@ -4,6 +4,8 @@
 // Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
 //
 
+use std::str::FromStr;
+
 /// Setting for the Asr prayer time.
 /// For Hanafi madhab, the Asr is bit later
 /// than that of the Shafi madhab.
@@ -19,6 +21,19 @@ impl Madhab {
     }
 }
 
+impl FromStr for Madhab {
+    type Err = String;
+
+    /// Parses `"Shafi"` (or `"Shafii"`) and `"Hanafi"`, ignoring case.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_lowercase().as_str() {
+            "shafi" | "shafii" => Ok(Madhab::Shafi),
+            "hanafi" => Ok(Madhab::Hanafi),
+            _ => Err(format!("Unknown madhab \"{}\".", value)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -36,4 +51,11 @@ mod tests {
 
         assert_eq!(hanafi.shadow(), 2);
     }
+
+    #[test]
+    fn parse_madhab() {
+        assert_eq!("Hanafi".parse::<Madhab>(), Ok(Madhab::Hanafi));
+        assert_eq!("shafi".parse::<Madhab>(), Ok(Madhab::Shafi));
+        assert!("Maliki".parse::<Madhab>().is_err());
+    }
 }
@@ -4,10 +4,23 @@
 // Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
 //
 
+use std::str::FromStr;
+
+use crate::astronomy::unit::Coordinates;
+
 use super::adjustments::Adjustment;
 use super::parameters::{Configuration, Parameters};
 use super::rounding::Rounding;
 
+/// A source of the parameters used for calculating prayer times.
+///
+/// Implement this to plug in a custom method (e.g. one used by a
+/// local committee) instead of one of the [Method](enum.Method.html)
+/// presets.
+pub trait CalculationMethod {
+    fn parameters(&self) -> Parameters;
+}
+
 /// Provides preset configuration for a few authorities
 /// for calculating prayer times.
 #[derive(PartialEq, Debug, Copy, Clone)]
@@ -27,7 +40,7 @@ pub enum Method {
     /// Umm al-Qura University, Makkah. Uses a fixed interval of 90 minutes
     /// from maghrib to calculate Isha. And a slightly earlier Fajr time with
     /// an angle of 18.5°. Note: you should add a +30 minute custom adjustment
-    /// for Isha during Ramadan.
+    /// for Isha during Ramadan, or enable `ramadan_isha_extension`.
     UmmAlQura,
 
     /// Used in the UAE. Slightly earlier Fajr time and slightly later Isha
@@ -71,6 +84,58 @@ pub enum Method {
 }
 
 impl Method {
+    /// A sensible default method for the given location.
+    ///
+    /// This is only a heuristic based on rough regional boundaries; it
+    /// should be confirmed against what the local community follows.
+    pub fn recommended(coordinates: &Coordinates) -> Method {
+        let latitude = coordinates.latitude;
+        let longitude = coordinates.longitude;
+        let within = |south: f64, north: f64, west: f64, east: f64| {
+            latitude >= south && latitude <= north && longitude >= west && longitude <= east
+        };
+        // Iran lies north of the Persian Gulf, leaving out
+        // Kuwait and the east of Saudi Arabia.
+        let in_iran = within(26.5, 40.0, 46.0, 63.5) && !within(26.5, 30.1, 46.0, 50.5);
+
+        if within(49.0, 61.0, -11.0, 2.0) {
+            // The United Kingdom and Ireland
+            Method::MoonsightingCommittee
+        } else if within(-60.0, 85.0, -170.0, -30.0) {
+            // The Americas
+            if latitude >= 55.0 {
+                Method::MoonsightingCommittee
+            } else if latitude >= 15.0 {
+                Method::NorthAmerica
+            } else {
+                Method::MuslimWorldLeague
+            }
+        } else if within(22.0, 26.5, 51.8, 56.5) {
+            Method::Dubai
+        } else if within(24.4, 26.2, 50.7, 51.7) {
+            Method::Qatar
+        } else if within(28.5, 30.1, 46.5, 48.5) {
+            Method::Kuwait
+        } else if within(12.0, 31.0, 34.5, 60.0) && !in_iran {
+            // The Arabian Peninsula
+            Method::UmmAlQura
+        } else if within(22.0, 32.0, 25.0, 35.0) {
+            Method::Egyptian
+        } else if within(36.0, 42.0, 26.0, 45.0) {
+            Method::Turkey
+        } else if in_iran {
+            Method::Tehran
+        } else if within(5.0, 37.0, 60.0, 97.0) {
+            // Pakistan, India, and Bangladesh
+            Method::Karachi
+        } else if within(-11.0, 7.5, 95.0, 141.0) {
+            // Singapore, Malaysia, and Indonesia
+            Method::Singapore
+        } else {
+            Method::MuslimWorldLeague
+        }
+    }
+
     pub fn parameters(&self) -> Parameters {
         match self {
             Method::MuslimWorldLeague => Configuration::new(18.0, 17.0)
@@ -94,19 +159,13 @@ impl Method {
                 .done(),
             Method::Dubai => Configuration::new(18.2, 18.2)
                 .method(*self)
-                .method_adjustments(
-                    Adjustment::new()
-                        .sunrise(-3)
-                        .dhuhr(3)
-                        .asr(3)
-                        .maghrib(3)
-                        .done(),
-                )
+                .method_adjustments(Adjustment::dubai().done())
                 .done(),
 
             Method::MoonsightingCommittee => Configuration::new(18.0, 18.0)
                 .method(*self)
                 .method_adjustments(Adjustment::new().dhuhr(5).maghrib(3).done())
+                .one_seventh_above_55(true)
                 .done(),
 
             Method::NorthAmerica => Configuration::new(15.0, 15.0)
@@ -149,10 +208,59 @@ impl Method {
     }
 }
 
+impl CalculationMethod for Method {
+    fn parameters(&self) -> Parameters {
+        Method::parameters(self)
+    }
+}
+
+impl FromStr for Method {
+    type Err = String;
+
+    /// Parses the name of a method, ignoring case, spaces, dashes, and
+    /// underscores (e.g. `"umm-al-qura"`), or one of the common
+    /// abbreviations `"MWL"`, `"ISNA"`, and `"MSC"`.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let name: String = value
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .collect::<String>()
+            .to_lowercase();
+
+        match name.as_str() {
+            "muslimworldleague" | "mwl" => Ok(Method::MuslimWorldLeague),
+            "egyptian" | "egypt" => Ok(Method::Egyptian),
+            "karachi" => Ok(Method::Karachi),
+            "ummalqura" => Ok(Method::UmmAlQura),
+            "dubai" => Ok(Method::Dubai),
+            "moonsightingcommittee" | "msc" => Ok(Method::MoonsightingCommittee),
+            "northamerica" | "isna" => Ok(Method::NorthAmerica),
+            "kuwait" => Ok(Method::Kuwait),
+            "qatar" => Ok(Method::Qatar),
+            "singapore" => Ok(Method::Singapore),
+            "tehran" => Ok(Method::Tehran),
+            "turkey" => Ok(Method::Turkey),
+            "other" => Ok(Method::Other),
+            _ => Err(format!("Unknown calculation method \"{}\".", value)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn parse_method() {
+        assert_eq!("ISNA".parse::<Method>(), Ok(Method::NorthAmerica));
+        assert_eq!("umm-al-qura".parse::<Method>(), Ok(Method::UmmAlQura));
+        assert_eq!(
+            "Moonsighting Committee".parse::<Method>(),
+            Ok(Method::MoonsightingCommittee)
+        );
+        assert!("Makkah".parse::<Method>().is_err());
+    }
+
     #[test]
     fn parameters_for_muslim_world_league() {
         let method = Method::MuslimWorldLeague;
@@ -273,4 +381,37 @@ mod tests {
         assert_eq!(params.isha_angle, 0.0);
         assert_eq!(params.isha_interval, 0);
     }
+
+    #[test]
+    fn recommended_method_for_location() {
+        let cases = [
+            (Coordinates::new(40.7128, -74.0059), Method::NorthAmerica),
+            (
+                Coordinates::new(61.2181, -149.9003),
+                Method::MoonsightingCommittee,
+            ),
+            (
+                Coordinates::new(51.5074, -0.1278),
+                Method::MoonsightingCommittee,
+            ),
+            (Coordinates::makkah(), Method::UmmAlQura),
+            (Coordinates::new(25.2048, 55.2708), Method::Dubai),
+            (Coordinates::new(26.4207, 50.0888), Method::UmmAlQura),
+            (Coordinates::new(30.0444, 31.2357), Method::Egyptian),
+            (Coordinates::new(41.0082, 28.9784), Method::Turkey),
+            (Coordinates::new(35.6892, 51.3890), Method::Tehran),
+            (Coordinates::new(24.8607, 67.0011), Method::Karachi),
+            (Coordinates::new(-6.2088, 106.8456), Method::Singapore),
+            (Coordinates::new(48.8566, 2.3522), Method::MuslimWorldLeague),
+        ];
+
+        for (coordinates, method) in cases.iter() {
+            assert_eq!(
+                Method::recommended(coordinates),
+                *method,
+                "{:?}",
+                coordinates
+            );
+        }
+    }
 }
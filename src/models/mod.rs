@@ -5,10 +5,18 @@
 //
 
 pub mod adjustments;
+pub mod calendar;
 pub mod high_altitude_rule;
+pub mod hijri;
+pub mod isha_fixed_time;
 pub mod madhab;
 pub mod method;
+pub mod night_definition;
 pub mod parameters;
 pub mod prayer;
+pub mod qiyam_base;
+pub mod reason;
 pub mod rounding;
 pub mod shafaq;
+pub mod solar_day_basis;
+pub mod twilight_status;
@@ -0,0 +1,18 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+/// The span of the night whose middle is the Islamic midnight,
+/// e.g. the end of the preferred time for Isha.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum NightDefinition {
+    /// The night lasts from Maghrib until the Fajr of the next day.
+    /// This is the most widely used definition and the default value.
+    MaghribToFajr,
+
+    /// The night lasts from sunset until sunrise of the next day,
+    /// which results in a later midnight.
+    SunsetToSunrise,
+}
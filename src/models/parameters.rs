@@ -4,13 +4,36 @@
 // Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
 //
 
+use std::fmt;
+
+use chrono::{Duration, FixedOffset, NaiveTime};
+
+use crate::astronomy::unit::{Angle, Coordinates};
+
 use super::adjustments::TimeAdjustment;
 use super::high_altitude_rule::HighLatitudeRule;
+use super::isha_fixed_time::IshaFixedTime;
 use super::madhab::Madhab;
 use super::method::Method;
+use super::night_definition::NightDefinition;
 use super::prayer::Prayer;
+use super::qiyam_base::QiyamBase;
 use super::rounding::Rounding;
 use super::shafaq::Shafaq;
+use super::solar_day_basis::SolarDayBasis;
+
+// Fajr angles used by the adaptive Fajr option, as pairs of the
+// upper latitude bound (exclusive) and the angle for that band.
+const ADAPTIVE_FAJR_ANGLES: [(f64, f64); 4] =
+    [(48.0, 18.0), (52.0, 17.0), (56.0, 16.0), (60.0, 15.0)];
+
+// Fajr angle used by the adaptive Fajr option above
+// the last latitude band.
+const ADAPTIVE_FAJR_POLAR_ANGLE: f64 = 14.0;
+
+// Degrees subtracted from (Ahmer) or added to (Abyad) the Isha
+// angle when shafaq is set for methods other than MoonsightingCommittee.
+const SHAFAQ_ANGLE_OFFSET: f64 = 3.0;
 
 /// Settings that are used for determining the
 /// the correct prayer time.
@@ -25,11 +48,79 @@ pub struct Parameters {
     pub isha_angle: f64,
     pub isha_interval: i32,
     pub madhab: Madhab,
-    pub high_latitude_rule: HighLatitudeRule,
+    /// The high latitude rule used for the earliest time of Fajr.
+    pub fajr_high_latitude_rule: HighLatitudeRule,
+    /// The high latitude rule used for the latest time of Isha.
+    pub isha_high_latitude_rule: HighLatitudeRule,
     pub adjustments: TimeAdjustment,
     pub method_adjustments: TimeAdjustment,
     pub rounding: Rounding,
     pub shafaq: Shafaq,
+    /// The *ikhtiyati* safety margin, in minutes, used by the
+    /// Indonesian and Malaysian authorities. It is added to Fajr,
+    /// Dhuhr, Asr, Maghrib, and Isha, and subtracted from sunrise.
+    pub safety_margin_minutes: i64,
+    pub qiyam_base: QiyamBase,
+    /// When enabled, the Fajr angle is selected based on the latitude
+    /// of the location instead of using `fajr_angle`. The angle starts at
+    /// 18° below 48° latitude and tapers off at higher latitudes.
+    pub adaptive_fajr: bool,
+    /// The length, in minutes, of the window around solar noon
+    /// (*zawal*) during which voluntary prayers are disliked.
+    /// The window is centered on the transit of the sun.
+    pub zawal_window_minutes: i64,
+    /// When enabled, the Isha interval of the Umm al-Qura method is
+    /// extended by 30 minutes (to 120 minutes) during Ramadan, based
+    /// on the tabular Islamic calendar.
+    pub ramadan_isha_extension: bool,
+    /// The minutes that Dhuhr is delayed after the transit of the sun,
+    /// to be certain that the sun has passed the meridian. This is
+    /// applied before, and separately from, the Dhuhr adjustments.
+    pub dhuhr_delay_minutes: i64,
+    /// The minimum number of minutes between consecutive prayers (Fajr,
+    /// Dhuhr, Asr, Maghrib, and Isha). When set, a prayer that is too close to
+    /// the previous one is moved later, which distorts the astronomical times.
+    /// Sunrise is left unchanged. Disabled when zero.
+    pub min_gap_minutes: i64,
+    /// A high latitude safeguard that limits Asr to the given fraction
+    /// (`0.0` to `1.0`) of the interval from Dhuhr to Maghrib, for days when
+    /// the shadow based Asr is very late relative to the length of the day.
+    /// Disabled when `None`.
+    pub asr_max_fraction: Option<f64>,
+    /// When enabled, Fajr and Isha are placed a seventh of the night
+    /// from sunrise and sunset at latitudes of 55° and above. The
+    /// Moonsighting Committee method enables it.
+    pub one_seventh_above_55: bool,
+    /// The fraction of the night, as `1/divisor`, used to place Fajr
+    /// and Isha when `one_seventh_above_55` applies.
+    pub high_latitude_divisor: u32,
+    /// A fixed clock time used for Isha when the angle for Isha is
+    /// not reached, or is reached after that time. The Isha adjustments
+    /// are applied to it like to any other Isha. Disabled when `None`.
+    pub isha_fixed_time: Option<IshaFixedTime>,
+    /// The minutes that Maghrib is delayed after sunset (*ihtiyat*), to
+    /// be certain that the sun has set. This is applied before, and
+    /// separately from, the Maghrib adjustments.
+    pub maghrib_delay_minutes: i64,
+    /// The day that the solar calculations are based on for the date.
+    pub solar_day_basis: SolarDayBasis,
+    /// When enabled, the altitude of the sun for Asr is calculated with
+    /// the declination at the approximate time of Asr rather than the
+    /// declination of the day. The difference is below a minute.
+    pub precise_asr: bool,
+    /// The span of the night whose middle is the Islamic midnight.
+    pub night_definition: NightDefinition,
+    /// The altitude of the sun at sunrise and sunset. The default of
+    /// −50′ accounts for refraction and the upper limb of the sun.
+    pub sunrise_altitude: Angle,
+    /// The minimum number of minutes that Isha is kept after Maghrib
+    /// when the calculated Isha would be earlier, e.g. after a large
+    /// negative Isha adjustment. See `PrayerTimes::is_clamped`.
+    pub min_isha_gap_minutes: i64,
+    /// The interval between Maghrib and Isha as a `Duration`, used in
+    /// place of `isha_interval` when set. Unlike `isha_interval`, it is
+    /// not limited to whole minutes.
+    pub isha_after_maghrib: Option<Duration>,
 }
 
 impl Parameters {
@@ -41,24 +132,133 @@ impl Parameters {
             method: Method::Other,
             isha_interval: 0,
             madhab: Madhab::Shafi,
-            high_latitude_rule: HighLatitudeRule::MiddleOfTheNight,
+            fajr_high_latitude_rule: HighLatitudeRule::MiddleOfTheNight,
+            isha_high_latitude_rule: HighLatitudeRule::MiddleOfTheNight,
             adjustments: TimeAdjustment::default(),
             method_adjustments: TimeAdjustment::default(),
             rounding: Rounding::Nearest,
             shafaq: Shafaq::General,
+            safety_margin_minutes: 0,
+            qiyam_base: QiyamBase::Maghrib,
+            adaptive_fajr: false,
+            zawal_window_minutes: 10,
+            ramadan_isha_extension: false,
+            dhuhr_delay_minutes: 0,
+            min_gap_minutes: 0,
+            asr_max_fraction: None,
+            one_seventh_above_55: false,
+            high_latitude_divisor: 7,
+            isha_fixed_time: None,
+            maghrib_delay_minutes: 0,
+            solar_day_basis: SolarDayBasis::UtcMidnight,
+            precise_asr: false,
+            night_definition: NightDefinition::MaghribToFajr,
+            sunrise_altitude: Angle::SUN_HORIZON_ALTITUDE,
+            min_isha_gap_minutes: 0,
+            isha_after_maghrib: None,
+        }
+    }
+
+    /// The Fajr angle to use for the given latitude, taking
+    /// the adaptive Fajr option into account.
+    pub fn effective_fajr_angle(&self, latitude: f64) -> f64 {
+        if self.adaptive_fajr {
+            ADAPTIVE_FAJR_ANGLES
+                .iter()
+                .find(|(upper_latitude, _)| latitude.abs() < *upper_latitude)
+                .map(|(_, angle)| *angle)
+                .unwrap_or(ADAPTIVE_FAJR_POLAR_ANGLE)
+        } else {
+            self.fajr_angle
         }
     }
 
+    /// The Isha angle to use, taking shafaq into account. The
+    /// MoonsightingCommittee method applies shafaq through its seasonal
+    /// adjustments instead, so its Isha angle is used as is. For the other
+    /// methods, `Ahmer` lowers the angle by 3° (the red glow disappears
+    /// earlier) and `Abyad` raises it by 3° (the white glow lasts longer).
+    pub fn effective_isha_angle(&self) -> f64 {
+        if self.method == Method::MoonsightingCommittee {
+            self.isha_angle
+        } else {
+            match self.shafaq {
+                Shafaq::General => self.isha_angle,
+                Shafaq::Ahmer => self.isha_angle - SHAFAQ_ANGLE_OFFSET,
+                Shafaq::Abyad => self.isha_angle + SHAFAQ_ANGLE_OFFSET,
+            }
+        }
+    }
+
+    /// The depression angle of the sun, below the horizon, that is used
+    /// for the given prayer: the Fajr and Isha angles, and the depression
+    /// at sunrise and sunset for Sunrise and Maghrib. Returns `None` for
+    /// the prayers that are not based on an angle, and for Isha when it
+    /// is set as an interval after Maghrib. The adaptive Fajr angle depends
+    /// on the latitude, see [effective_fajr_angle](#method.effective_fajr_angle).
+    pub fn angle_for(&self, prayer: Prayer) -> Option<Angle> {
+        match prayer {
+            Prayer::Fajr | Prayer::FajrTomorrow => Some(Angle::new(self.fajr_angle)),
+            Prayer::Sunrise | Prayer::Maghrib => Some(Angle::new(-self.sunrise_altitude.degrees)),
+            Prayer::Isha => {
+                if self.isha_interval_duration() > Duration::zero() {
+                    None
+                } else {
+                    Some(Angle::new(self.effective_isha_angle()))
+                }
+            }
+            Prayer::Dhuhr | Prayer::Asr | Prayer::Qiyam => None,
+        }
+    }
+
+    /// The portions of the night used for the earliest Fajr and
+    /// the latest Isha, based on their respective high latitude rules.
     pub fn night_portions(&self) -> (f64, f64) {
-        match self.high_latitude_rule {
+        let fajr_portion = self.rule_night_portions(self.fajr_high_latitude_rule).0;
+        let isha_portion = self.rule_night_portions(self.isha_high_latitude_rule).1;
+
+        (fajr_portion, isha_portion)
+    }
+
+    fn rule_night_portions(&self, rule: HighLatitudeRule) -> (f64, f64) {
+        match rule {
             HighLatitudeRule::MiddleOfTheNight => (1.0 / 2.0, 1.0 / 2.0),
             HighLatitudeRule::SeventhOfTheNight => (1.0 / 7.0, 1.0 / 7.0),
-            HighLatitudeRule::TwilightAngle => (self.fajr_angle / 60.0, self.isha_angle / 60.0),
+            HighLatitudeRule::TwilightAngle => {
+                (self.fajr_angle / 60.0, self.effective_isha_angle() / 60.0)
+            }
+            HighLatitudeRule::AngleProportional => {
+                // Split half of the night, so that the two
+                // portions never use up the whole night.
+                let total = self.fajr_angle + self.effective_isha_angle();
+
+                (
+                    self.fajr_angle / total / 2.0,
+                    self.effective_isha_angle() / total / 2.0,
+                )
+            }
         }
     }
 
+    /// The interval between Maghrib and Isha, when Isha is
+    /// based on an interval instead of an angle.
+    pub fn isha_interval_duration(&self) -> Duration {
+        self.isha_after_maghrib
+            .unwrap_or_else(|| Duration::minutes(self.isha_interval as i64))
+    }
+
+    /// Returns a copy of the parameters without the adjustments of the
+    /// method, leaving the user adjustments in place. This isolates the
+    /// times given by the angles from the offsets applied by the method.
+    pub fn without_method_adjustments(&self) -> Parameters {
+        let mut parameters = *self;
+        parameters.method_adjustments = TimeAdjustment::default();
+
+        parameters
+    }
+
     pub fn time_adjustments(&self, prayer: Prayer) -> i64 {
-        match prayer {
+        let adjustment = match prayer {
             Prayer::Fajr => self.adjustments.fajr + self.method_adjustments.fajr,
             Prayer::Sunrise => self.adjustments.sunrise + self.method_adjustments.sunrise,
             Prayer::Dhuhr => self.adjustments.dhuhr + self.method_adjustments.dhuhr,
@@ -66,10 +266,62 @@ impl Parameters {
             Prayer::Maghrib => self.adjustments.maghrib + self.method_adjustments.maghrib,
             Prayer::Isha => self.adjustments.isha + self.method_adjustments.isha,
             _ => 0,
+        };
+
+        adjustment + self.safety_margin(prayer)
+    }
+
+    /// The safety margin (*ikhtiyati*) for the given prayer.
+    /// Sunrise gets the margin subtracted so that the end of
+    /// the Fajr time is never overstated.
+    pub fn safety_margin(&self, prayer: Prayer) -> i64 {
+        match prayer {
+            Prayer::Fajr | Prayer::Dhuhr | Prayer::Asr | Prayer::Maghrib | Prayer::Isha => {
+                self.safety_margin_minutes
+            }
+            Prayer::Sunrise => -self.safety_margin_minutes,
+            _ => 0,
         }
     }
 }
 
+impl fmt::Display for Parameters {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Method: {:?}", self.method)?;
+        writeln!(f, "Fajr angle: {}°", self.fajr_angle)?;
+        writeln!(f, "Maghrib angle: {}°", self.maghrib_angle)?;
+        writeln!(f, "Isha angle: {}°", self.isha_angle)?;
+        writeln!(
+            f,
+            "Isha interval: {} minutes",
+            self.isha_interval_duration().num_seconds() as f64 / 60.0
+        )?;
+        writeln!(f, "Madhab: {:?}", self.madhab)?;
+        writeln!(
+            f,
+            "Fajr high latitude rule: {:?}",
+            self.fajr_high_latitude_rule
+        )?;
+        writeln!(
+            f,
+            "Isha high latitude rule: {:?}",
+            self.isha_high_latitude_rule
+        )?;
+        writeln!(f, "Rounding: {:?}", self.rounding)?;
+        writeln!(f, "Shafaq: {:?}", self.shafaq)?;
+        write!(
+            f,
+            "Adjustments: fajr {}, sunrise {}, dhuhr {}, asr {}, maghrib {}, isha {}",
+            self.time_adjustments(Prayer::Fajr),
+            self.time_adjustments(Prayer::Sunrise),
+            self.time_adjustments(Prayer::Dhuhr),
+            self.time_adjustments(Prayer::Asr),
+            self.time_adjustments(Prayer::Maghrib),
+            self.time_adjustments(Prayer::Isha)
+        )
+    }
+}
+
 /// A builder for the the [Parameters](struct.Parameters.html).
 ///
 /// It is recommended that this is used for setting
@@ -81,11 +333,30 @@ pub struct Configuration {
     isha_angle: f64,
     isha_interval: i32,
     madhab: Madhab,
-    high_latitude_rule: HighLatitudeRule,
+    fajr_high_latitude_rule: HighLatitudeRule,
+    isha_high_latitude_rule: HighLatitudeRule,
     adjustments: TimeAdjustment,
     method_adjustments: TimeAdjustment,
     rounding: Rounding,
     shafaq: Shafaq,
+    safety_margin_minutes: i64,
+    qiyam_base: QiyamBase,
+    adaptive_fajr: bool,
+    zawal_window_minutes: i64,
+    ramadan_isha_extension: bool,
+    dhuhr_delay_minutes: i64,
+    min_gap_minutes: i64,
+    asr_max_fraction: Option<f64>,
+    one_seventh_above_55: bool,
+    high_latitude_divisor: u32,
+    isha_fixed_time: Option<IshaFixedTime>,
+    maghrib_delay_minutes: i64,
+    solar_day_basis: SolarDayBasis,
+    precise_asr: bool,
+    night_definition: NightDefinition,
+    sunrise_altitude: Angle,
+    min_isha_gap_minutes: i64,
+    isha_after_maghrib: Option<Duration>,
 }
 
 impl Configuration {
@@ -97,11 +368,30 @@ impl Configuration {
             method: Method::Other,
             isha_interval: 0,
             madhab: Madhab::Shafi,
-            high_latitude_rule: HighLatitudeRule::MiddleOfTheNight,
+            fajr_high_latitude_rule: HighLatitudeRule::MiddleOfTheNight,
+            isha_high_latitude_rule: HighLatitudeRule::MiddleOfTheNight,
             adjustments: TimeAdjustment::default(),
             method_adjustments: TimeAdjustment::default(),
             rounding: Rounding::Nearest,
             shafaq: Shafaq::General,
+            safety_margin_minutes: 0,
+            qiyam_base: QiyamBase::Maghrib,
+            adaptive_fajr: false,
+            zawal_window_minutes: 10,
+            ramadan_isha_extension: false,
+            dhuhr_delay_minutes: 0,
+            min_gap_minutes: 0,
+            asr_max_fraction: None,
+            one_seventh_above_55: false,
+            high_latitude_divisor: 7,
+            isha_fixed_time: None,
+            maghrib_delay_minutes: 0,
+            solar_day_basis: SolarDayBasis::UtcMidnight,
+            precise_asr: false,
+            night_definition: NightDefinition::MaghribToFajr,
+            sunrise_altitude: Angle::SUN_HORIZON_ALTITUDE,
+            min_isha_gap_minutes: 0,
+            isha_after_maghrib: None,
         }
     }
 
@@ -125,14 +415,41 @@ impl Configuration {
         self
     }
 
+    /// Sets the high latitude rule for both Fajr and Isha.
     pub fn high_latitude_rule<'a>(
         &'a mut self,
         high_latitude_rule: HighLatitudeRule,
     ) -> &'a mut Configuration {
-        self.high_latitude_rule = high_latitude_rule;
+        self.fajr_high_latitude_rule = high_latitude_rule;
+        self.isha_high_latitude_rule = high_latitude_rule;
+        self
+    }
+
+    pub fn fajr_high_latitude_rule<'a>(
+        &'a mut self,
+        high_latitude_rule: HighLatitudeRule,
+    ) -> &'a mut Configuration {
+        self.fajr_high_latitude_rule = high_latitude_rule;
+        self
+    }
+
+    pub fn isha_high_latitude_rule<'a>(
+        &'a mut self,
+        high_latitude_rule: HighLatitudeRule,
+    ) -> &'a mut Configuration {
+        self.isha_high_latitude_rule = high_latitude_rule;
         self
     }
 
+    /// Sets the high latitude rule to the one
+    /// recommended for the given location.
+    pub fn recommended_high_latitude_rule<'a>(
+        &'a mut self,
+        coordinates: &Coordinates,
+    ) -> &'a mut Configuration {
+        self.high_latitude_rule(HighLatitudeRule::recommended(*coordinates))
+    }
+
     pub fn madhab<'a>(&'a mut self, madhab: Madhab) -> &'a mut Configuration {
         self.madhab = madhab;
         self
@@ -141,6 +458,17 @@ impl Configuration {
     pub fn isha_interval<'a>(&'a mut self, isha_interval: i32) -> &'a mut Configuration {
         self.isha_angle = 0.0;
         self.isha_interval = isha_interval;
+        self.isha_after_maghrib = None;
+        self
+    }
+
+    /// Sets Isha to the given interval after Maghrib, in place of the
+    /// Isha angle. The same as `isha_interval`, with the interval given
+    /// as a `Duration`, which is kept as is.
+    pub fn isha_after_maghrib<'a>(&'a mut self, interval: Duration) -> &'a mut Configuration {
+        self.isha_angle = 0.0;
+        self.isha_interval = 0;
+        self.isha_after_maghrib = Some(interval);
         self
     }
 
@@ -159,6 +487,98 @@ impl Configuration {
         self
     }
 
+    pub fn safety_margin<'a>(&'a mut self, minutes: i64) -> &'a mut Configuration {
+        self.safety_margin_minutes = minutes;
+        self
+    }
+
+    pub fn qiyam_base<'a>(&'a mut self, value: QiyamBase) -> &'a mut Configuration {
+        self.qiyam_base = value;
+        self
+    }
+
+    pub fn adaptive_fajr<'a>(&'a mut self, enabled: bool) -> &'a mut Configuration {
+        self.adaptive_fajr = enabled;
+        self
+    }
+
+    pub fn zawal_window<'a>(&'a mut self, minutes: i64) -> &'a mut Configuration {
+        self.zawal_window_minutes = minutes;
+        self
+    }
+
+    pub fn ramadan_isha_extension<'a>(&'a mut self, enabled: bool) -> &'a mut Configuration {
+        self.ramadan_isha_extension = enabled;
+        self
+    }
+
+    pub fn dhuhr_delay<'a>(&'a mut self, minutes: i64) -> &'a mut Configuration {
+        self.dhuhr_delay_minutes = minutes;
+        self
+    }
+
+    pub fn min_gap<'a>(&'a mut self, minutes: i64) -> &'a mut Configuration {
+        self.min_gap_minutes = minutes;
+        self
+    }
+
+    pub fn asr_max_fraction<'a>(&'a mut self, fraction: f64) -> &'a mut Configuration {
+        self.asr_max_fraction = Some(fraction);
+        self
+    }
+
+    pub fn one_seventh_above_55<'a>(&'a mut self, enabled: bool) -> &'a mut Configuration {
+        self.one_seventh_above_55 = enabled;
+        self
+    }
+
+    pub fn high_latitude_divisor<'a>(&'a mut self, divisor: u32) -> &'a mut Configuration {
+        self.high_latitude_divisor = divisor;
+        self
+    }
+
+    pub fn isha_fixed_time<'a>(
+        &'a mut self,
+        time: NaiveTime,
+        offset: FixedOffset,
+    ) -> &'a mut Configuration {
+        self.isha_fixed_time = Some(IshaFixedTime::new(time, offset));
+        self
+    }
+
+    pub fn maghrib_delay<'a>(&'a mut self, minutes: i64) -> &'a mut Configuration {
+        self.maghrib_delay_minutes = minutes;
+        self
+    }
+
+    pub fn solar_day_basis<'a>(&'a mut self, basis: SolarDayBasis) -> &'a mut Configuration {
+        self.solar_day_basis = basis;
+        self
+    }
+
+    pub fn precise_asr<'a>(&'a mut self, enabled: bool) -> &'a mut Configuration {
+        self.precise_asr = enabled;
+        self
+    }
+
+    pub fn night_definition<'a>(
+        &'a mut self,
+        definition: NightDefinition,
+    ) -> &'a mut Configuration {
+        self.night_definition = definition;
+        self
+    }
+
+    pub fn sunrise_altitude<'a>(&'a mut self, altitude: Angle) -> &'a mut Configuration {
+        self.sunrise_altitude = altitude;
+        self
+    }
+
+    pub fn min_isha_gap<'a>(&'a mut self, minutes: i64) -> &'a mut Configuration {
+        self.min_isha_gap_minutes = minutes;
+        self
+    }
+
     pub fn done(&self) -> Parameters {
         Parameters {
             fajr_angle: self.fajr_angle,
@@ -167,11 +587,30 @@ impl Configuration {
             method: self.method,
             isha_interval: self.isha_interval,
             madhab: self.madhab,
-            high_latitude_rule: self.high_latitude_rule,
+            fajr_high_latitude_rule: self.fajr_high_latitude_rule,
+            isha_high_latitude_rule: self.isha_high_latitude_rule,
             adjustments: self.adjustments,
             method_adjustments: self.method_adjustments,
             rounding: self.rounding,
             shafaq: self.shafaq,
+            safety_margin_minutes: self.safety_margin_minutes,
+            qiyam_base: self.qiyam_base,
+            adaptive_fajr: self.adaptive_fajr,
+            zawal_window_minutes: self.zawal_window_minutes,
+            ramadan_isha_extension: self.ramadan_isha_extension,
+            dhuhr_delay_minutes: self.dhuhr_delay_minutes,
+            min_gap_minutes: self.min_gap_minutes,
+            asr_max_fraction: self.asr_max_fraction,
+            one_seventh_above_55: self.one_seventh_above_55,
+            high_latitude_divisor: self.high_latitude_divisor,
+            isha_fixed_time: self.isha_fixed_time,
+            maghrib_delay_minutes: self.maghrib_delay_minutes,
+            solar_day_basis: self.solar_day_basis,
+            precise_asr: self.precise_asr,
+            night_definition: self.night_definition,
+            sunrise_altitude: self.sunrise_altitude,
+            min_isha_gap_minutes: self.min_isha_gap_minutes,
+            isha_after_maghrib: self.isha_after_maghrib,
         }
     }
 }
@@ -180,6 +619,22 @@ impl Configuration {
 mod tests {
     use super::*;
 
+    #[test]
+    fn angle_for_each_prayer() {
+        let params = Method::MuslimWorldLeague.parameters();
+        let umm_al_qura = Method::UmmAlQura.parameters();
+
+        assert_eq!(params.angle_for(Prayer::Fajr), Some(Angle::new(18.0)));
+        assert_eq!(params.angle_for(Prayer::Isha), Some(Angle::new(17.0)));
+        assert_eq!(
+            params.angle_for(Prayer::Maghrib).unwrap().degrees,
+            50.0 / 60.0
+        );
+        assert_eq!(params.angle_for(Prayer::Asr), None);
+        assert_eq!(params.angle_for(Prayer::Dhuhr), None);
+        assert_eq!(umm_al_qura.angle_for(Prayer::Isha), None);
+    }
+
     #[test]
     fn calculate_parameters_with_fajr_and_isha_angles() {
         let params = Parameters::new(18.0, 18.0);
@@ -217,6 +672,16 @@ mod tests {
         assert_eq!(params.night_portions().1, 15.0 / 60.0);
     }
 
+    #[test]
+    fn calculated_night_portions_angle_proportional() {
+        let params = Configuration::new(18.0, 12.0)
+            .high_latitude_rule(HighLatitudeRule::AngleProportional)
+            .done();
+
+        assert_eq!(params.night_portions().0, 18.0 / 30.0 / 2.0);
+        assert_eq!(params.night_portions().1, 12.0 / 30.0 / 2.0);
+    }
+
     #[test]
     fn parameters_using_method_and_madhab() {
         let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
@@ -227,4 +692,61 @@ mod tests {
         assert_eq!(params.isha_interval, 0);
         assert_eq!(params.madhab, Madhab::Hanafi);
     }
+
+    #[test]
+    fn adaptive_fajr_angle_by_latitude() {
+        let params = Configuration::new(18.0, 17.0).adaptive_fajr(true).done();
+
+        assert_eq!(params.effective_fajr_angle(40.0), 18.0);
+        assert_eq!(params.effective_fajr_angle(-40.0), 18.0);
+        assert_eq!(params.effective_fajr_angle(50.0), 17.0);
+        assert_eq!(params.effective_fajr_angle(60.0), 14.0);
+    }
+
+    #[test]
+    fn fixed_fajr_angle_by_latitude() {
+        let params = Configuration::new(15.0, 15.0).done();
+
+        assert_eq!(params.effective_fajr_angle(40.0), 15.0);
+        assert_eq!(params.effective_fajr_angle(60.0), 15.0);
+    }
+
+    #[test]
+    fn display_parameters() {
+        let params = Configuration::with(Method::MuslimWorldLeague, Madhab::Shafi);
+        let summary = params.to_string();
+
+        assert!(summary.contains("Method: MuslimWorldLeague"));
+        assert!(summary.contains("Fajr angle: 18°"));
+        assert!(summary.contains("Isha angle: 17°"));
+        assert!(summary.contains("dhuhr 1,"));
+    }
+
+    #[test]
+    fn recommended_high_latitude_rule_for_location() {
+        let london = Coordinates::new(50.0, -0.1278);
+        let params = Configuration::new(18.0, 17.0)
+            .recommended_high_latitude_rule(&london)
+            .done();
+
+        assert_eq!(
+            params.fajr_high_latitude_rule,
+            HighLatitudeRule::SeventhOfTheNight
+        );
+        assert_eq!(
+            params.isha_high_latitude_rule,
+            HighLatitudeRule::SeventhOfTheNight
+        );
+    }
+
+    #[test]
+    fn calculated_night_portions_with_separate_rules() {
+        let params = Configuration::new(18.0, 18.0)
+            .fajr_high_latitude_rule(HighLatitudeRule::MiddleOfTheNight)
+            .isha_high_latitude_rule(HighLatitudeRule::SeventhOfTheNight)
+            .done();
+
+        assert_eq!(params.night_portions().0, 1.0 / 2.0);
+        assert_eq!(params.night_portions().1, 1.0 / 7.0);
+    }
 }
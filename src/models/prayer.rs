@@ -8,7 +8,10 @@ use chrono::{Datelike, Utc, Weekday};
 
 /// Names of all obligatory prayers,
 /// sunrise, and Qiyam.
-#[derive(PartialEq, Debug, Copy, Clone)]
+///
+/// Prayers are ordered by their position in the day, from
+/// Fajr to Qiyam, followed by the Fajr of the next day.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Clone)]
 pub enum Prayer {
     Fajr,
     Sunrise,
@@ -21,12 +24,36 @@ pub enum Prayer {
 }
 
 impl Prayer {
+    /// The five obligatory prayers, in the order of the day.
+    pub fn all() -> [Prayer; 5] {
+        [
+            Prayer::Fajr,
+            Prayer::Dhuhr,
+            Prayer::Asr,
+            Prayer::Maghrib,
+            Prayer::Isha,
+        ]
+    }
+
+    /// Whether the prayer is one of the five obligatory daily
+    /// prayers, as opposed to sunrise, Qiyam, or the Fajr of
+    /// the next day.
+    pub fn is_daily(&self) -> bool {
+        Prayer::all().contains(self)
+    }
+
     pub fn name(&self) -> String {
+        self.name_on(Utc::now().weekday())
+    }
+
+    /// The name of the prayer on the given day of the week,
+    /// where Dhuhr is called Jumua on Fridays.
+    pub fn name_on(&self, weekday: Weekday) -> String {
         match self {
             Prayer::Fajr | Prayer::FajrTomorrow => String::from("Fajr"),
             Prayer::Sunrise => String::from("Sunrise"),
             Prayer::Dhuhr => {
-                if Utc::now().weekday() == Weekday::Fri {
+                if weekday == Weekday::Fri {
                     String::from("Jumua")
                 } else {
                     String::from("Dhuhr")
@@ -60,4 +87,67 @@ mod tests {
         assert_eq!(Prayer::Isha.name(), "Isha");
         assert_eq!(Prayer::Qiyam.name(), "Qiyam");
     }
+
+    #[test]
+    fn daily_prayers_are_obligatory() {
+        assert!(Prayer::Fajr.is_daily());
+        assert!(Prayer::Isha.is_daily());
+        assert!(!Prayer::Sunrise.is_daily());
+        assert!(!Prayer::Qiyam.is_daily());
+        assert!(!Prayer::FajrTomorrow.is_daily());
+    }
+
+    #[test]
+    fn prayer_name_on_a_given_day() {
+        assert_eq!(Prayer::Dhuhr.name_on(Weekday::Fri), "Jumua");
+        assert_eq!(Prayer::Dhuhr.name_on(Weekday::Sat), "Dhuhr");
+        assert_eq!(Prayer::Asr.name_on(Weekday::Fri), "Asr");
+    }
+
+    #[test]
+    fn all_obligatory_prayers_in_order() {
+        let prayers = Prayer::all();
+
+        assert_eq!(prayers.len(), 5);
+        assert_eq!(
+            prayers,
+            [
+                Prayer::Fajr,
+                Prayer::Dhuhr,
+                Prayer::Asr,
+                Prayer::Maghrib,
+                Prayer::Isha
+            ]
+        );
+    }
+
+    #[test]
+    fn prayers_sorted_in_daily_order() {
+        let mut prayers = vec![
+            Prayer::Isha,
+            Prayer::FajrTomorrow,
+            Prayer::Sunrise,
+            Prayer::Qiyam,
+            Prayer::Asr,
+            Prayer::Fajr,
+            Prayer::Maghrib,
+            Prayer::Dhuhr,
+        ];
+        prayers.sort();
+
+        assert_eq!(
+            prayers,
+            vec![
+                Prayer::Fajr,
+                Prayer::Sunrise,
+                Prayer::Dhuhr,
+                Prayer::Asr,
+                Prayer::Maghrib,
+                Prayer::Isha,
+                Prayer::Qiyam,
+                Prayer::FajrTomorrow
+            ]
+        );
+        assert!(Prayer::Maghrib < Prayer::Isha);
+    }
 }
@@ -0,0 +1,19 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+/// The start of the night that is used when dividing
+/// it into thirds for the Qiyam (tahajjud) prayer.
+/// In both cases the night ends at Fajr.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum QiyamBase {
+    /// The night is measured from Maghrib. This is the default value.
+    Maghrib,
+
+    /// The night is measured from Isha, as defined by some scholars
+    /// for the purpose of the tahajjud prayer. This results in a later
+    /// time for the last third of the night.
+    Isha,
+}
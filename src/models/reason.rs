@@ -0,0 +1,23 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+/// The reasons why voluntary prayers are
+/// disliked (*makruh*) at a given time.
+///
+/// Reasons are ordered by when their window occurs in the
+/// day: after Sunrise, before Dhuhr, and before Maghrib.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Clone)]
+pub enum Reason {
+    /// The sun is rising.
+    DuringSunrise,
+
+    /// The sun is at its zenith (*istiwa*), right
+    /// before the time for Dhuhr begins.
+    DuringZawal,
+
+    /// The sun is setting.
+    DuringSunset,
+}
@@ -4,9 +4,92 @@
 // Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
 //
 
-#[derive(PartialEq, Debug, Copy, Clone)]
+use std::mem;
+
+use chrono::{DateTime, TimeZone, Utc};
+
+use crate::astronomy::unit::Stride;
+
+#[derive(Debug, Copy, Clone)]
 pub enum Rounding {
     Nearest,
     Up,
+    /// Truncates to the minute, discarding the seconds. Unlike
+    /// `Nearest`, this never moves the time to the next minute.
+    Down,
+    /// Keeps the calculated times, which are precise to the second.
     None,
+    /// Rounds to the nearest multiple of the given number of
+    /// seconds (of the day), e.g. `ToSeconds(30)` for 30 second
+    /// granularity. Zero leaves the time unchanged.
+    ToSeconds(u32),
+    /// Rounds with the given function. The time is passed to the
+    /// function in UTC and converted back to the original timezone.
+    /// Functions can not be compared reliably, so any two `Custom`
+    /// roundings are equal.
+    Custom(fn(DateTime<Utc>) -> DateTime<Utc>),
+}
+
+impl PartialEq for Rounding {
+    fn eq(&self, other: &Rounding) -> bool {
+        match (self, other) {
+            (Rounding::ToSeconds(seconds), Rounding::ToSeconds(other_seconds)) => {
+                seconds == other_seconds
+            }
+            _ => mem::discriminant(self) == mem::discriminant(other),
+        }
+    }
+}
+
+/// A way of rounding the calculated times. It is implemented by
+/// [Rounding](enum.Rounding.html), whose `Custom` variant can be used
+/// to plug a bespoke strategy into the [Parameters](struct.Parameters.html).
+pub trait RoundingStrategy {
+    fn round<Tz: TimeZone>(&self, dt: DateTime<Tz>) -> DateTime<Tz>;
+}
+
+impl RoundingStrategy for Rounding {
+    fn round<Tz: TimeZone>(&self, dt: DateTime<Tz>) -> DateTime<Tz> {
+        dt.rounded_minute(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, FixedOffset, Timelike};
+
+    fn nearest_ten_minutes(dt: DateTime<Utc>) -> DateTime<Utc> {
+        let seconds = (dt.minute() % 10 * 60 + dt.second()) as i64;
+
+        if seconds >= 300 {
+            dt + Duration::seconds(600 - seconds)
+        } else {
+            dt - Duration::seconds(seconds)
+        }
+    }
+
+    #[test]
+    fn round_with_custom_strategy() {
+        let rounding = Rounding::Custom(nearest_ten_minutes);
+        let time = Utc.ymd(2015, 7, 13).and_hms(4, 37, 30);
+        let local_time = time.with_timezone(&FixedOffset::east(5 * 3600));
+
+        assert_eq!(rounding.round(time), Utc.ymd(2015, 7, 13).and_hms(4, 40, 0));
+        assert_eq!(
+            rounding.round(Utc.ymd(2015, 7, 13).and_hms(4, 34, 59)),
+            Utc.ymd(2015, 7, 13).and_hms(4, 30, 0)
+        );
+        assert_eq!(
+            rounding.round(local_time),
+            local_time + Duration::seconds(150)
+        );
+        assert_eq!(
+            Rounding::Nearest.round(time),
+            Utc.ymd(2015, 7, 13).and_hms(4, 38, 0)
+        );
+        assert_eq!(rounding, Rounding::Custom(nearest_ten_minutes));
+        assert_ne!(Rounding::ToSeconds(30), Rounding::ToSeconds(15));
+        assert_ne!(rounding, Rounding::Nearest);
+    }
 }
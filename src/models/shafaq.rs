@@ -6,7 +6,9 @@
 
 /// Shafaq is the twilight in the sky. Different madhabs define the appearance of
 /// twilight differently. These values are used by the MoonsightingComittee method
-/// for the different ways to calculate Isha.
+/// for the different ways to calculate Isha. Other methods map them to a
+/// lower (`Ahmer`) or higher (`Abyad`) Isha angle; see
+/// [Parameters::effective_isha_angle](struct.Parameters.html#method.effective_isha_angle).
 #[derive(PartialEq, Debug, Copy, Clone)]
 pub enum Shafaq {
     /// General is a combination of Ahmer and Abyad.
@@ -0,0 +1,23 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+use chrono::FixedOffset;
+
+/// The day that the solar calculations are based on
+/// for the date of a schedule.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum SolarDayBasis {
+    /// The solar day of the date, starting at midnight UTC. This is
+    /// the default value, and is correct when the local date and the
+    /// UTC date agree at noon, which is the case for most locations.
+    UtcMidnight,
+
+    /// The solar day whose noon falls on the date at the given UTC
+    /// offset. Use the offset of the location for places far from
+    /// their time zone meridian, e.g. near the date line (UTC+14),
+    /// where noon on the UTC date falls on the next local date.
+    LocalMidnight(FixedOffset),
+}
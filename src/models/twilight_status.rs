@@ -0,0 +1,28 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+/// Describes whether the sun gets low enough during the
+/// night for the twilight based prayers (Fajr and Isha)
+/// to occur astronomically.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum TwilightStatus {
+    /// The sun reaches the depression angle needed
+    /// for Fajr and Isha.
+    Occurs,
+
+    /// The sun sets, but never gets low enough for the
+    /// twilight to disappear. This happens roughly between
+    /// 48° and 66° latitude around the summer solstice;
+    /// the times for Fajr and Isha are then approximated
+    /// using the high latitude rule.
+    PersistentTwilight,
+
+    /// The sun does not rise during the day.
+    PolarNight,
+
+    /// The sun does not set during the day.
+    MidnightSun,
+}
@@ -9,42 +9,237 @@
 //! This module provides the main objects that are used for calculating
 //! the prayer times.
 
-use chrono::{Date, DateTime, Datelike, Duration, Utc};
+use std::fmt;
+use std::iter::FromIterator;
+
+use chrono::{
+    Date, DateTime, Datelike, Duration, FixedOffset, Local, NaiveDate, NaiveDateTime, TimeZone,
+    Timelike, Utc, Weekday,
+};
 
 use crate::astronomy::ops;
-use crate::astronomy::solar::SolarTime;
-use crate::astronomy::unit::{Angle, Coordinates, Stride};
-use crate::models::method::Method;
-use crate::models::parameters::Parameters;
+use crate::astronomy::solar::{SolarError, SolarTime};
+use crate::astronomy::unit::{Angle, Coordinates, Minutes, Stride};
+use crate::models::hijri::Hijri;
+use crate::models::madhab::Madhab;
+use crate::models::method::{CalculationMethod, Method};
+use crate::models::night_definition::NightDefinition;
+use crate::models::parameters::{Configuration, Parameters};
 use crate::models::prayer::Prayer;
+use crate::models::qiyam_base::QiyamBase;
+use crate::models::reason::Reason;
 use crate::models::rounding::Rounding;
+use crate::models::solar_day_basis::SolarDayBasis;
+use crate::models::twilight_status::TwilightStatus;
+
+// The length, in minutes, of the disliked window that
+// starts when the sun rises.
+const SUNRISE_RESTRICTION_MINUTES: i64 = 15;
+
+// The length, in minutes, of the disliked window that
+// ends when the sun sets.
+const SUNSET_RESTRICTION_MINUTES: i64 = 15;
+
+// The extra minutes added to the Isha interval
+// of Umm al-Qura during Ramadan.
+const RAMADAN_ISHA_EXTENSION_MINUTES: i64 = 30;
+
+// The middle of the night, the last third of the
+// night and tomorrow's Fajr, in that order.
+type NightTimes = (DateTime<Utc>, DateTime<Utc>, DateTime<Utc>);
 
 /// A data struct to hold the timing for all
 /// prayers.
-#[derive(PartialEq, Debug, Copy, Clone)]
-pub struct PrayerTimes {
-    fajr: DateTime<Utc>,
-    sunrise: DateTime<Utc>,
-    dhuhr: DateTime<Utc>,
-    asr: DateTime<Utc>,
-    maghrib: DateTime<Utc>,
-    isha: DateTime<Utc>,
-    middle_of_the_night: DateTime<Utc>,
-    qiyam: DateTime<Utc>,
-    fajr_tomorrow: DateTime<Utc>,
+///
+/// The times are calculated in UTC; use
+/// [with_timezone](#method.with_timezone) to get
+/// the same schedule expressed in another timezone.
+#[derive(PartialEq, Debug, Clone)]
+pub struct PrayerTimes<Tz: TimeZone = Utc> {
+    fajr: DateTime<Tz>,
+    sunrise: DateTime<Tz>,
+    dhuhr: DateTime<Tz>,
+    asr: DateTime<Tz>,
+    maghrib: DateTime<Tz>,
+    isha: DateTime<Tz>,
+    middle_of_the_night: DateTime<Tz>,
+    qiyam: DateTime<Tz>,
+    fajr_tomorrow: DateTime<Tz>,
     coordinates: Coordinates,
     date: DateTime<Utc>,
     parameters: Parameters,
+    clamped: bool,
+}
+
+impl<Tz: TimeZone> Copy for PrayerTimes<Tz>
+where
+    Tz: Copy,
+    Tz::Offset: Copy,
+{
 }
 
 impl PrayerTimes {
+    /// Calculates the prayer times for the given date and location.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the sun does not rise or does not set around the given
+    /// date; use [try_new](#method.try_new) to handle that case.
     pub fn new(date: Date<Utc>, coordinates: Coordinates, parameters: Parameters) -> PrayerTimes {
+        PrayerTimes::try_new(date, coordinates, parameters)
+            .expect("The sun does not rise or set at the given date and location")
+    }
+
+    /// Calculates the prayer times for the given date and location.
+    /// Fails when the sun does not rise or does not set around the
+    /// given date, e.g. near the poles.
+    pub fn try_new(
+        date: Date<Utc>,
+        coordinates: Coordinates,
+        parameters: Parameters,
+    ) -> Result<PrayerTimes, SolarError> {
         let prayer_date = date.and_hms(0, 0, 0);
-        let tomorrow = prayer_date.tomorrow();
-        let solar_time = SolarTime::new(prayer_date, coordinates);
-        let solar_time_tomorrow = SolarTime::new(tomorrow, coordinates);
+        let solar_date = PrayerTimes::solar_date(date, coordinates, parameters)?;
+        let altitude = parameters.sunrise_altitude;
+        let solar_time = SolarTime::with_altitude(solar_date, coordinates, altitude)?;
+        let solar_time_tomorrow =
+            SolarTime::with_altitude(solar_date.tomorrow(), coordinates, altitude)?;
+
+        PrayerTimes::from_solar_times(
+            prayer_date,
+            solar_date,
+            solar_time,
+            solar_time_tomorrow,
+            coordinates,
+            parameters,
+        )
+    }
+
+    /// Calculates the prayer times at the point found at the given
+    /// fraction (`0.0` to `1.0`) of the great circle path between the
+    /// start and end coordinates, e.g. the position along a flight.
+    /// Returns `None` when the coordinates are antipodal, since the
+    /// path between them is not unique.
+    pub fn at_fraction(
+        start: Coordinates,
+        end: Coordinates,
+        fraction: f64,
+        date: Date<Utc>,
+        parameters: Parameters,
+    ) -> Option<PrayerTimes> {
+        start
+            .interpolate(&end, fraction)
+            .map(|coordinates| PrayerTimes::new(date, coordinates, parameters))
+    }
+
+    /// Calculates the schedule for both the Shafi and the Hanafi madhab
+    /// (in that order), ignoring the madhab set in the parameters. The
+    /// solar calculations are shared, since only Asr differs between them.
+    pub fn both_madhabs(
+        date: Date<Utc>,
+        coordinates: Coordinates,
+        parameters: Parameters,
+    ) -> (PrayerTimes, PrayerTimes) {
+        PrayerTimes::try_both_madhabs(date, coordinates, parameters)
+            .expect("The sun does not rise or set at the given date and location")
+    }
+
+    /// Calculates the schedule once and expresses it in each of the
+    /// given offsets, in the same order. This is cheaper than calculating
+    /// the schedule for every offset, since the instants are the same.
+    pub fn for_offsets(
+        date: Date<Utc>,
+        coordinates: Coordinates,
+        parameters: Parameters,
+        offsets: &[FixedOffset],
+    ) -> Vec<PrayerTimes<FixedOffset>> {
+        let times = PrayerTimes::new(date, coordinates, parameters);
+
+        offsets
+            .iter()
+            .map(|offset| times.with_timezone(offset))
+            .collect()
+    }
+
+    fn try_both_madhabs(
+        date: Date<Utc>,
+        coordinates: Coordinates,
+        parameters: Parameters,
+    ) -> Result<(PrayerTimes, PrayerTimes), SolarError> {
+        let prayer_date = date.and_hms(0, 0, 0);
+        let solar_date = PrayerTimes::solar_date(date, coordinates, parameters)?;
+        let altitude = parameters.sunrise_altitude;
+        let solar_time = SolarTime::with_altitude(solar_date, coordinates, altitude)?;
+        let solar_time_tomorrow =
+            SolarTime::with_altitude(solar_date.tomorrow(), coordinates, altitude)?;
+        let mut shafi_parameters = parameters;
+        let mut hanafi_parameters = parameters;
+        shafi_parameters.madhab = Madhab::Shafi;
+        hanafi_parameters.madhab = Madhab::Hanafi;
+
+        Ok((
+            PrayerTimes::from_solar_times(
+                prayer_date,
+                solar_date,
+                solar_time,
+                solar_time_tomorrow,
+                coordinates,
+                shafi_parameters,
+            )?,
+            PrayerTimes::from_solar_times(
+                prayer_date,
+                solar_date,
+                solar_time,
+                solar_time_tomorrow,
+                coordinates,
+                hanafi_parameters,
+            )?,
+        ))
+    }
+
+    // The start of the UTC day that the solar calculations are
+    // based on for the date, according to the solar day basis.
+    fn solar_date(
+        date: Date<Utc>,
+        coordinates: Coordinates,
+        parameters: Parameters,
+    ) -> Result<DateTime<Utc>, SolarError> {
+        let prayer_date = date.and_hms(0, 0, 0);
+
+        match parameters.solar_day_basis {
+            SolarDayBasis::UtcMidnight => Ok(prayer_date),
+            SolarDayBasis::LocalMidnight(offset) => {
+                // Use the day whose noon falls on the local date.
+                let transit = SolarTime::with_altitude(
+                    prayer_date,
+                    coordinates,
+                    parameters.sunrise_altitude,
+                )?
+                .transit;
+                let local_date = transit.with_timezone(&offset).date_naive();
+                let days = local_date
+                    .signed_duration_since(date.naive_utc())
+                    .num_days();
+
+                Ok(prayer_date - Duration::days(days))
+            }
+        }
+    }
 
-        let asr = solar_time.afternoon(parameters.madhab.shadow().into());
+    fn from_solar_times(
+        prayer_date: DateTime<Utc>,
+        solar_date: DateTime<Utc>,
+        solar_time: SolarTime,
+        solar_time_tomorrow: SolarTime,
+        coordinates: Coordinates,
+        parameters: Parameters,
+    ) -> Result<PrayerTimes, SolarError> {
+        let tomorrow = solar_date.tomorrow();
+        let asr = if parameters.precise_asr {
+            solar_time.precise_afternoon(parameters.madhab.shadow().into())
+        } else {
+            solar_time.afternoon(parameters.madhab.shadow().into())
+        };
         let night = solar_time_tomorrow
             .sunrise
             .signed_duration_since(solar_time.sunset);
@@ -54,32 +249,53 @@ impl PrayerTimes {
 				.rounded_minute(parameters.rounding);
         let final_sunrise = solar_time
             .sunrise
-            .adjust_time(parameters.time_adjustments(Prayer::Sunrise))
-			.rounded_minute(parameters.rounding);
-        let final_dhuhr = solar_time
-            .transit
-            .adjust_time(parameters.time_adjustments(Prayer::Dhuhr))
-			.rounded_minute(parameters.rounding);
-        let final_asr = asr.adjust_time(parameters.time_adjustments(Prayer::Asr))
-			.rounded_minute(parameters.rounding);
-        let final_maghrib = ops::adjust_time(
-            &solar_time.sunset,
-            parameters.time_adjustments(Prayer::Maghrib)).rounded_minute(parameters.rounding);
+            .adjust_time(Minutes(parameters.time_adjustments(Prayer::Sunrise)))
+            .rounded_minute(parameters.rounding);
+        let final_dhuhr = PrayerTimes::calculate_dhuhr(parameters, solar_time)
+            .rounded_minute(parameters.rounding);
+        let final_asr = asr
+            .adjust_time(Minutes(parameters.time_adjustments(Prayer::Asr)))
+            .rounded_minute(parameters.rounding);
+        let final_maghrib = PrayerTimes::calculate_maghrib(parameters, solar_time)
+            .rounded_minute(parameters.rounding);
         let final_isha =
             PrayerTimes::calculate_isha(parameters, solar_time, night, coordinates, prayer_date)
 				.rounded_minute(parameters.rounding);
 
+        let final_asr = PrayerTimes::capped_asr(final_asr, final_dhuhr, final_maghrib, parameters);
+
+        // Keep consecutive prayers at least the minimum gap apart.
+        let gap = parameters.min_gap_minutes;
+        let final_dhuhr = PrayerTimes::after_min_gap(final_fajr, final_dhuhr, gap);
+        let final_asr = PrayerTimes::after_min_gap(final_dhuhr, final_asr, gap);
+        let final_maghrib = PrayerTimes::after_min_gap(final_asr, final_maghrib, gap);
+        let final_isha = PrayerTimes::after_min_gap(final_maghrib, final_isha, gap);
+
+        // Keep the prayers in order, e.g. when a large negative
+        // adjustment moves a prayer before the previous one.
+        let (final_sunrise, sunrise_clamped) =
+            PrayerTimes::not_before(final_fajr, final_sunrise, 0);
+        let (final_dhuhr, dhuhr_clamped) = PrayerTimes::not_before(final_sunrise, final_dhuhr, 0);
+        let (final_asr, asr_clamped) = PrayerTimes::not_before(final_dhuhr, final_asr, 0);
+        let (final_maghrib, maghrib_clamped) = PrayerTimes::not_before(final_asr, final_maghrib, 0);
+        let (final_isha, isha_clamped) =
+            PrayerTimes::not_before(final_maghrib, final_isha, parameters.min_isha_gap_minutes);
+        let clamped =
+            sunrise_clamped || dhuhr_clamped || asr_clamped || maghrib_clamped || isha_clamped;
+
         // Calculate the middle of the night and qiyam times
         let (final_middle_of_night, final_qiyam, final_fajr_tomorrow) =
             PrayerTimes::calculate_qiyam(
+                solar_time.sunset,
                 final_maghrib,
+                final_isha,
                 parameters,
                 solar_time_tomorrow,
                 coordinates,
                 tomorrow,
-            );
+            )?;
 
-        PrayerTimes {
+        Ok(PrayerTimes {
             fajr: final_fajr,
             sunrise: final_sunrise,
             dhuhr: final_dhuhr,
@@ -92,19 +308,125 @@ impl PrayerTimes {
             coordinates: coordinates,
             date: prayer_date,
             parameters: parameters,
-        }
+            clamped: clamped,
+        })
     }
+}
 
-    pub fn time(&self, prayer: Prayer) -> DateTime<Utc> {
+impl<Tz: TimeZone> PrayerTimes<Tz> {
+    pub fn time(&self, prayer: Prayer) -> DateTime<Tz> {
         match prayer {
-            Prayer::Fajr => self.fajr,
-            Prayer::Sunrise => self.sunrise,
-            Prayer::Dhuhr => self.dhuhr,
-            Prayer::Asr => self.asr,
-            Prayer::Maghrib => self.maghrib,
-            Prayer::Isha => self.isha,
-            Prayer::Qiyam => self.qiyam,
-            Prayer::FajrTomorrow => self.fajr_tomorrow,
+            Prayer::Fajr => self.fajr.clone(),
+            Prayer::Sunrise => self.sunrise.clone(),
+            Prayer::Dhuhr => self.dhuhr.clone(),
+            Prayer::Asr => self.asr.clone(),
+            Prayer::Maghrib => self.maghrib.clone(),
+            Prayer::Isha => self.isha.clone(),
+            Prayer::Qiyam => self.qiyam.clone(),
+            Prayer::FajrTomorrow => self.fajr_tomorrow.clone(),
+        }
+    }
+
+    /// The Islamic midnight, the middle of the night
+    /// as set by `night_definition`.
+    pub fn middle_of_the_night(&self) -> DateTime<Tz> {
+        self.middle_of_the_night.clone()
+    }
+
+    /// The start and end of the night whose middle is the Islamic
+    /// midnight: Maghrib and the Fajr of the next day, or sunset and
+    /// sunrise of the next day, depending on `night_definition`.
+    pub fn night_span(&self) -> (DateTime<Tz>, DateTime<Tz>) {
+        match self.parameters.night_definition {
+            NightDefinition::MaghribToFajr => (self.maghrib.clone(), self.fajr_tomorrow.clone()),
+            NightDefinition::SunsetToSunrise => {
+                // The times of the sun were already calculated for the
+                // schedule, so the sun rises and sets on both days.
+                let tz = self.maghrib.timezone();
+                let sunset = self.solar_time(0).unwrap().sunset;
+                let sunrise = self.solar_time(1).unwrap().sunrise;
+
+                (sunset.with_timezone(&tz), sunrise.with_timezone(&tz))
+            }
+        }
+    }
+
+    /// The coordinates of the location
+    /// these times were calculated for.
+    pub fn coordinates(&self) -> &Coordinates {
+        &self.coordinates
+    }
+
+    /// Whether a prayer time was moved to keep the prayers in order,
+    /// e.g. an Isha that would otherwise be before Maghrib. The
+    /// clamped times do not follow the parameters, which should be
+    /// reviewed.
+    pub fn is_clamped(&self) -> bool {
+        self.clamped
+    }
+
+    /// Whether the schedule was calculated for a Friday,
+    /// when Dhuhr is replaced by the Jumua prayer.
+    pub fn is_jumuah_day(&self) -> bool {
+        self.date.weekday() == Weekday::Fri
+    }
+
+    /// The time at which the Islamic day rolls over to the next
+    /// date. The Islamic day starts at Maghrib, not at midnight.
+    pub fn islamic_date_boundary(&self) -> &DateTime<Tz> {
+        &self.maghrib
+    }
+
+    /// Returns whether the given time is at or after Maghrib, meaning
+    /// that it already belongs to the next Islamic (Hijri) day.
+    pub fn is_after_islamic_midnight<Tz2: TimeZone>(&self, time: DateTime<Tz2>) -> bool {
+        time >= self.maghrib
+    }
+
+    /// Returns how far into the night the given time is, from `0.0`
+    /// at Maghrib to `1.0` at Fajr of the next day. The night is always
+    /// measured from Maghrib, regardless of `night_definition` and
+    /// `qiyam_base`. Returns `None` when the time is not during that night.
+    pub fn night_fraction(&self, now: &DateTime<Tz>) -> Option<f64> {
+        if *now >= self.maghrib && *now <= self.fajr_tomorrow {
+            let night_duration = self
+                .fajr_tomorrow
+                .clone()
+                .signed_duration_since(self.maghrib.clone())
+                .num_seconds() as f64;
+            let elapsed = now
+                .clone()
+                .signed_duration_since(self.maghrib.clone())
+                .num_seconds() as f64;
+
+            Some(elapsed / night_duration)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the same schedule with all of the times
+    /// expressed in the given timezone.
+    ///
+    /// The times are instants, so the conversion is never ambiguous, even
+    /// on days when daylight saving time starts or ends: a local time that
+    /// is skipped by the transition can not occur, and times after the
+    /// transition are expressed with the new offset.
+    pub fn with_timezone<Tz2: TimeZone>(&self, tz: &Tz2) -> PrayerTimes<Tz2> {
+        PrayerTimes {
+            fajr: self.fajr.with_timezone(tz),
+            sunrise: self.sunrise.with_timezone(tz),
+            dhuhr: self.dhuhr.with_timezone(tz),
+            asr: self.asr.with_timezone(tz),
+            maghrib: self.maghrib.with_timezone(tz),
+            isha: self.isha.with_timezone(tz),
+            middle_of_the_night: self.middle_of_the_night.with_timezone(tz),
+            qiyam: self.qiyam.with_timezone(tz),
+            fajr_tomorrow: self.fajr_tomorrow.with_timezone(tz),
+            coordinates: self.coordinates,
+            date: self.date,
+            parameters: self.parameters,
+            clamped: self.clamped,
         }
     }
 
@@ -137,24 +459,375 @@ impl PrayerTimes {
         (hours, minutes)
     }
 
-    fn current_time(&self, time: DateTime<Utc>) -> Option<Prayer> {
+    /// Returns the same schedule expressed in the given timezone, with
+    /// every time rounded *after* the conversion. The times are normally
+    /// rounded in UTC during the calculation; use this together with
+    /// `Rounding::None` in the parameters to round in the target timezone.
+    pub fn rounded_in<Tz2: TimeZone>(&self, tz: &Tz2, rounding: Rounding) -> PrayerTimes<Tz2> {
+        let mut times = self.with_timezone(tz);
+
+        times.fajr = times.fajr.rounded_minute(rounding);
+        times.sunrise = times.sunrise.rounded_minute(rounding);
+        times.dhuhr = times.dhuhr.rounded_minute(rounding);
+        times.asr = times.asr.rounded_minute(rounding);
+        times.maghrib = times.maghrib.rounded_minute(rounding);
+        times.isha = times.isha.rounded_minute(rounding);
+        times.middle_of_the_night = times.middle_of_the_night.rounded_minute(rounding);
+        times.qiyam = times.qiyam.rounded_minute(rounding);
+        times.fajr_tomorrow = times.fajr_tomorrow.rounded_minute(rounding);
+
+        times
+    }
+
+    /// Returns the same schedule with all of the times in UTC.
+    pub fn as_utc(&self) -> PrayerTimes<Utc> {
+        self.with_timezone(&Utc)
+    }
+
+    /// Returns the same schedule with all of the times
+    /// in the local timezone of the system.
+    pub fn as_local(&self) -> PrayerTimes<Local> {
+        self.with_timezone(&Local)
+    }
+
+    /// Returns the most recent obligatory prayer whose time is at
+    /// or before the given time. When the given time is before
+    /// Fajr, Isha of the previous day is returned, or `None` when
+    /// the sun does not rise or does not set around the previous day.
+    pub fn previous_prayer<Tz2: TimeZone>(
+        &self,
+        time: DateTime<Tz2>,
+    ) -> Option<(Prayer, DateTime<Tz>)> {
+        let prayers = [
+            Prayer::Isha,
+            Prayer::Maghrib,
+            Prayer::Asr,
+            Prayer::Dhuhr,
+            Prayer::Fajr,
+        ];
+
+        for prayer in prayers.iter() {
+            let prayer_time = self.time(*prayer);
+
+            if prayer_time <= time {
+                return Some((*prayer, prayer_time));
+            }
+        }
+
+        PrayerTimes::try_new(
+            self.date.yesterday().date(),
+            self.coordinates,
+            self.parameters,
+        )
+        .ok()
+        .map(|yesterday| {
+            (
+                Prayer::Isha,
+                yesterday.isha.with_timezone(&self.fajr.timezone()),
+            )
+        })
+    }
+
+    /// Formats the schedule as a GitHub-flavored Markdown table,
+    /// with the prayer names as the header and their times
+    /// (`HH:MM`) as the only row. Dhuhr is named Jumua when
+    /// the schedule is for a Friday.
+    pub fn to_markdown(&self) -> String
+    where
+        Tz::Offset: fmt::Display,
+    {
+        let prayers = [
+            Prayer::Fajr,
+            Prayer::Sunrise,
+            Prayer::Dhuhr,
+            Prayer::Asr,
+            Prayer::Maghrib,
+            Prayer::Isha,
+        ];
+        let mut header = String::from("|");
+        let mut divider = String::from("|");
+        let mut row = String::from("|");
+
+        for prayer in prayers.iter() {
+            header.push_str(&format!(" {} |", prayer.name_on(self.date.weekday())));
+            divider.push_str(" --- |");
+            row.push_str(&format!(" {} |", self.time(*prayer).format("%H:%M")));
+        }
+
+        format!("{}\n{}\n{}\n", header, divider, row)
+    }
+
+    /// Returns the five obligatory prayers of the day, in order,
+    /// along with their times.
+    pub fn daily_prayers(&self) -> Vec<(Prayer, &DateTime<Tz>)> {
+        vec![
+            (Prayer::Fajr, &self.fajr),
+            (Prayer::Sunrise, &self.sunrise),
+            (Prayer::Dhuhr, &self.dhuhr),
+            (Prayer::Asr, &self.asr),
+            (Prayer::Maghrib, &self.maghrib),
+            (Prayer::Isha, &self.isha),
+            (Prayer::Qiyam, &self.qiyam),
+            (Prayer::FajrTomorrow, &self.fajr_tomorrow),
+        ]
+        .into_iter()
+        .filter(|(prayer, _)| prayer.is_daily())
+        .collect()
+    }
+
+    /// Returns the position of each prayer as a fraction (`0.0` to `1.0`)
+    /// of the civil day, measured from midnight in the timezone of the
+    /// schedule. The denominator is always 24 hours, not the Islamic day
+    /// that starts at Maghrib.
+    pub fn as_fractions(&self) -> [(Prayer, f64); 6] {
+        let seconds_per_day = 24.0 * 60.0 * 60.0;
+        let fraction = |prayer: Prayer| {
+            let seconds = self.time(prayer).num_seconds_from_midnight() as f64;
+
+            (prayer, seconds / seconds_per_day)
+        };
+
+        [
+            fraction(Prayer::Fajr),
+            fraction(Prayer::Sunrise),
+            fraction(Prayer::Dhuhr),
+            fraction(Prayer::Asr),
+            fraction(Prayer::Maghrib),
+            fraction(Prayer::Isha),
+        ]
+    }
+
+    /// Returns the signed difference between the times of this schedule
+    /// and the other one for each prayer. Negative values mean that the
+    /// prayer is earlier in this schedule.
+    pub fn diff<Tz2: TimeZone>(&self, other: &PrayerTimes<Tz2>) -> [(Prayer, Duration); 6] {
+        let difference = |prayer: Prayer| {
+            (
+                prayer,
+                self.time(prayer).signed_duration_since(other.time(prayer)),
+            )
+        };
+
+        [
+            difference(Prayer::Fajr),
+            difference(Prayer::Sunrise),
+            difference(Prayer::Dhuhr),
+            difference(Prayer::Asr),
+            difference(Prayer::Maghrib),
+            difference(Prayer::Isha),
+        ]
+    }
+
+    /// Returns whether the sun gets low enough, on the day of this
+    /// schedule, for the astronomical Fajr and Isha to occur. When it
+    /// does not, the times for Fajr and Isha are approximations based
+    /// on the high latitude rule.
+    pub fn twilight_status(&self) -> TwilightStatus {
+        let solar_time = match self.solar_time(0) {
+            Ok(solar_time) => solar_time,
+            Err(SolarError::PolarNight) => return TwilightStatus::PolarNight,
+            Err(SolarError::MidnightSun) => return TwilightStatus::MidnightSun,
+        };
+        let fajr_angle = self
+            .parameters
+            .effective_fajr_angle(self.coordinates.latitude);
+
+        // Isha does not depend on the sun when it is an interval after sunset.
+        let depression = if self.parameters.isha_interval_duration() > Duration::zero() {
+            fajr_angle
+        } else {
+            fajr_angle.max(self.parameters.effective_isha_angle())
+        };
+
+        solar_time.twilight_status(Angle::new(depression))
+    }
+
+    /// Calculates the schedule for the next day, at the same location
+    /// and with the same parameters, in the timezone of this schedule.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the sun does not rise or does not set around the next day.
+    pub fn tomorrow(&self) -> PrayerTimes<Tz> {
+        let tomorrow = self.date.tomorrow().date();
+
+        PrayerTimes::new(tomorrow, self.coordinates, self.parameters)
+            .with_timezone(&self.fajr.timezone())
+    }
+
+    /// Returns a copy of the schedule with only the time of the given
+    /// prayer moved by the given minutes, e.g. while an adjustment is
+    /// being edited. The other times, including the ones derived from
+    /// that prayer such as Qiyam, and the parameters are unchanged.
+    pub fn with_prayer_adjustment(&self, prayer: Prayer, minutes: i64) -> PrayerTimes<Tz> {
+        let mut times = self.clone();
+        let time = match prayer {
+            Prayer::Fajr => &mut times.fajr,
+            Prayer::Sunrise => &mut times.sunrise,
+            Prayer::Dhuhr => &mut times.dhuhr,
+            Prayer::Asr => &mut times.asr,
+            Prayer::Maghrib => &mut times.maghrib,
+            Prayer::Isha => &mut times.isha,
+            Prayer::Qiyam => &mut times.qiyam,
+            Prayer::FajrTomorrow => &mut times.fajr_tomorrow,
+        };
+        *time = time.adjust_time(Minutes(minutes));
+
+        times
+    }
+
+    /// Linearly interpolates each time between this schedule (`t = 0.0`)
+    /// and the other one (`t = 1.0`), e.g. the schedule of the next day
+    /// for smooth animations. The coordinates, date, and parameters are
+    /// taken from the nearest of the two schedules.
+    ///
+    /// The result is an approximation, not the astronomical times for
+    /// a moment between the two dates.
+    pub fn lerp(&self, other: &PrayerTimes<Tz>, t: f64) -> PrayerTimes<Tz> {
+        let interpolate = |start: &DateTime<Tz>, end: &DateTime<Tz>| {
+            let span = end.clone().signed_duration_since(start.clone());
+            let offset = (span.num_milliseconds() as f64 * t).round() as i64;
+
+            start.clone() + Duration::milliseconds(offset)
+        };
+        let nearest = if t < 0.5 { self } else { other };
+
+        PrayerTimes {
+            fajr: interpolate(&self.fajr, &other.fajr),
+            sunrise: interpolate(&self.sunrise, &other.sunrise),
+            dhuhr: interpolate(&self.dhuhr, &other.dhuhr),
+            asr: interpolate(&self.asr, &other.asr),
+            maghrib: interpolate(&self.maghrib, &other.maghrib),
+            isha: interpolate(&self.isha, &other.isha),
+            middle_of_the_night: interpolate(&self.middle_of_the_night, &other.middle_of_the_night),
+            qiyam: interpolate(&self.qiyam, &other.qiyam),
+            fajr_tomorrow: interpolate(&self.fajr_tomorrow, &other.fajr_tomorrow),
+            coordinates: nearest.coordinates,
+            date: nearest.date,
+            parameters: nearest.parameters,
+            clamped: nearest.clamped,
+        }
+    }
+
+    /// Returns the length of the last third of the night, from the start
+    /// of Qiyam to Fajr of the next day. The start of the night is set by
+    /// `qiyam_base`. Never negative, even when the times are approximated
+    /// at high latitudes.
+    pub fn last_third_duration(&self) -> Duration {
+        let duration = self
+            .fajr_tomorrow
+            .clone()
+            .signed_duration_since(self.qiyam.clone());
+
+        if duration < Duration::zero() {
+            Duration::zero()
+        } else {
+            duration
+        }
+    }
+
+    /// Returns the durations of the night portions used by the high
+    /// latitude rules for the earliest Fajr and the latest Isha: the
+    /// fractions from `night_portions()` applied to the length of the
+    /// night, from sunset to sunrise of the next day.
+    pub fn night_portion_durations(&self) -> (Duration, Duration) {
+        // The times of the sun were already calculated for the
+        // schedule, so the sun rises and sets on both days.
+        let solar_time = self.solar_time(0).unwrap();
+        let solar_time_tomorrow = self.solar_time(1).unwrap();
+        let night_seconds = solar_time_tomorrow
+            .sunrise
+            .signed_duration_since(solar_time.sunset)
+            .num_seconds() as f64;
+        let (fajr_portion, isha_portion) = self.parameters.night_portions();
+
+        (
+            Duration::seconds((fajr_portion * night_seconds) as i64),
+            Duration::seconds((isha_portion * night_seconds) as i64),
+        )
+    }
+
+    /// Returns the reason voluntary prayers are disliked at the
+    /// current time, if any.
+    pub fn current_restriction(&self) -> Option<Reason> {
+        self.restriction(Utc::now())
+    }
+
+    /// Returns the reason voluntary prayers are disliked at the
+    /// given time, if any. The window for the zawal is centered
+    /// on the transit of the sun and its length is set by
+    /// `zawal_window_minutes`.
+    pub fn restriction<Tz2: TimeZone>(&self, time: DateTime<Tz2>) -> Option<Reason> {
+        self.makruh_windows()
+            .into_iter()
+            .find(|(_, start, end)| time >= *start && time < *end)
+            .map(|(reason, _, _)| reason)
+    }
+
+    /// Returns the windows during which voluntary prayers are disliked,
+    /// as the reason along with the start (inclusive) and end (exclusive)
+    /// of each window: after sunrise, around the zawal, and before sunset.
+    /// Returns no windows when the sun does not rise or set on the day.
+    pub fn makruh_windows(&self) -> Vec<(Reason, DateTime<Tz>, DateTime<Tz>)> {
+        let solar_time = match self.solar_time(0) {
+            Ok(solar_time) => solar_time,
+            Err(_) => return vec![],
+        };
+        let tz = self.sunrise.timezone();
+        let sunrise_end = solar_time.sunrise + Duration::minutes(SUNRISE_RESTRICTION_MINUTES);
+        let sunset_start = solar_time.sunset - Duration::minutes(SUNSET_RESTRICTION_MINUTES);
+        let zawal_half = Duration::seconds(self.parameters.zawal_window_minutes * 30);
+
+        vec![
+            (
+                Reason::DuringSunrise,
+                solar_time.sunrise.with_timezone(&tz),
+                sunrise_end.with_timezone(&tz),
+            ),
+            (
+                Reason::DuringZawal,
+                (solar_time.transit - zawal_half).with_timezone(&tz),
+                (solar_time.transit + zawal_half).with_timezone(&tz),
+            ),
+            (
+                Reason::DuringSunset,
+                sunset_start.with_timezone(&tz),
+                solar_time.sunset.with_timezone(&tz),
+            ),
+        ]
+    }
+
+    // The times of the sun for the given number of days
+    // after the solar day of the schedule.
+    fn solar_time(&self, days: i64) -> Result<SolarTime, SolarError> {
+        let solar_date =
+            PrayerTimes::solar_date(self.date.date(), self.coordinates, self.parameters)?;
+
+        SolarTime::with_altitude(
+            solar_date + Duration::days(days),
+            self.coordinates,
+            self.parameters.sunrise_altitude,
+        )
+    }
+
+    fn current_time<Tz2: TimeZone>(&self, time: DateTime<Tz2>) -> Option<Prayer> {
         let current_prayer: Option<Prayer>;
 
-        if self.fajr_tomorrow.signed_duration_since(time).num_seconds() <= 0 {
+        if self.fajr_tomorrow.clone().signed_duration_since(&time).num_seconds() <= 0 {
             current_prayer = Some(Prayer::FajrTomorrow)
-        } else if self.qiyam.signed_duration_since(time).num_seconds() <= 0 {
+        } else if self.qiyam.clone().signed_duration_since(&time).num_seconds() <= 0 {
             current_prayer = Some(Prayer::Qiyam)
-        } else if self.isha.signed_duration_since(time).num_seconds() <= 0 {
+        } else if self.isha.clone().signed_duration_since(&time).num_seconds() <= 0 {
             current_prayer = Some(Prayer::Isha);
-        } else if self.maghrib.signed_duration_since(time).num_seconds() <= 0 {
+        } else if self.maghrib.clone().signed_duration_since(&time).num_seconds() <= 0 {
             current_prayer = Some(Prayer::Maghrib);
-        } else if self.asr.signed_duration_since(time).num_seconds() <= 0 {
+        } else if self.asr.clone().signed_duration_since(&time).num_seconds() <= 0 {
             current_prayer = Some(Prayer::Asr);
-        } else if self.dhuhr.signed_duration_since(time).num_seconds() <= 0 {
+        } else if self.dhuhr.clone().signed_duration_since(&time).num_seconds() <= 0 {
             current_prayer = Some(Prayer::Dhuhr);
-        } else if self.sunrise.signed_duration_since(time).num_seconds() <= 0 {
+        } else if self.sunrise.clone().signed_duration_since(&time).num_seconds() <= 0 {
             current_prayer = Some(Prayer::Sunrise);
-        } else if self.fajr.signed_duration_since(time).num_seconds() <= 0 {
+        } else if self.fajr.clone().signed_duration_since(&time).num_seconds() <= 0 {
             current_prayer = Some(Prayer::Fajr);
         } else {
             current_prayer = None;
@@ -162,7 +835,9 @@ impl PrayerTimes {
 
         current_prayer
     }
+}
 
+impl PrayerTimes {
     fn calculate_fajr(
         parameters: Parameters,
         solar_time: SolarTime,
@@ -170,15 +845,15 @@ impl PrayerTimes {
         coordinates: Coordinates,
         prayer_date: DateTime<Utc>,
     ) -> DateTime<Utc> {
-        let mut fajr = solar_time.time_for_solar_angle(Angle::new(-parameters.fajr_angle), false);
+        let fajr_angle = parameters.effective_fajr_angle(coordinates.latitude);
+        let mut fajr = solar_time.time_for_solar_angle(Angle::new(-fajr_angle), false);
 
-        // special case for moonsighting committee above latitude 55
-        if parameters.method == Method::MoonsightingCommittee && coordinates.latitude >= 55.0 {
-            let night_fraction = night.num_seconds() / 7;
+        // a fraction of the night above latitude 55
+        if parameters.one_seventh_above_55 && coordinates.latitude >= 55.0 {
+            let night_fraction = night.num_seconds() / parameters.high_latitude_divisor as i64;
             fajr = solar_time
                 .sunrise
-                .checked_add_signed(Duration::seconds(-night_fraction))
-                .unwrap();
+                .checked_add_signed(Duration::seconds(-night_fraction));
         } else {
             // Nothing to do.
         }
@@ -201,13 +876,78 @@ impl PrayerTimes {
                 .unwrap()
         };
 
-        if fajr < safe_fajr {
-            fajr = safe_fajr;
+        // The high latitude rule is also used when the
+        // sun never reaches the angle for Fajr.
+        let final_fajr = match fajr {
+            Some(fajr) if fajr >= safe_fajr => fajr,
+            _ => safe_fajr,
+        };
+
+        final_fajr.adjust_time(Minutes(parameters.time_adjustments(Prayer::Fajr)))
+    }
+
+    fn capped_asr(
+        asr: DateTime<Utc>,
+        dhuhr: DateTime<Utc>,
+        maghrib: DateTime<Utc>,
+        parameters: Parameters,
+    ) -> DateTime<Utc> {
+        match parameters.asr_max_fraction {
+            Some(fraction) => {
+                let interval = maghrib.signed_duration_since(dhuhr).num_seconds() as f64;
+                let latest = (dhuhr + Duration::seconds((fraction * interval) as i64))
+                    .rounded_minute(parameters.rounding);
+
+                if asr > latest {
+                    latest
+                } else {
+                    asr
+                }
+            }
+            None => asr,
+        }
+    }
+
+    fn after_min_gap(
+        previous: DateTime<Utc>,
+        time: DateTime<Utc>,
+        gap_minutes: i64,
+    ) -> DateTime<Utc> {
+        let earliest = previous + Duration::minutes(gap_minutes);
+
+        if gap_minutes > 0 && time < earliest {
+            earliest
         } else {
-            // Nothing to do.
+            time
+        }
+    }
+
+    fn not_before(
+        previous: DateTime<Utc>,
+        time: DateTime<Utc>,
+        gap_minutes: i64,
+    ) -> (DateTime<Utc>, bool) {
+        let earliest = previous + Duration::minutes(gap_minutes);
+
+        if time < earliest {
+            (earliest, true)
+        } else {
+            (time, false)
         }
+    }
+
+    fn calculate_dhuhr(parameters: Parameters, solar_time: SolarTime) -> DateTime<Utc> {
+        solar_time
+            .transit
+            .adjust_time(Minutes(parameters.dhuhr_delay_minutes))
+            .adjust_time(Minutes(parameters.time_adjustments(Prayer::Dhuhr)))
+    }
 
-        fajr.adjust_time(parameters.time_adjustments(Prayer::Fajr))
+    fn calculate_maghrib(parameters: Parameters, solar_time: SolarTime) -> DateTime<Utc> {
+        solar_time
+            .sunset
+            .adjust_time(Minutes(parameters.maghrib_delay_minutes))
+            .adjust_time(Minutes(parameters.time_adjustments(Prayer::Maghrib)))
     }
 
     fn calculate_isha(
@@ -217,23 +957,31 @@ impl PrayerTimes {
         coordinates: Coordinates,
         prayer_date: DateTime<Utc>,
     ) -> DateTime<Utc> {
-        let mut isha: DateTime<Utc>;
+        let isha: DateTime<Utc>;
+
+        if parameters.isha_interval_duration() > Duration::zero() {
+            let mut isha_interval = parameters.isha_interval_duration();
+
+            if parameters.ramadan_isha_extension
+                && parameters.method == Method::UmmAlQura
+                && Hijri::from_gregorian(&prayer_date).is_ramadan()
+            {
+                isha_interval += Duration::minutes(RAMADAN_ISHA_EXTENSION_MINUTES);
+            } else {
+                // Nothing to do.
+            }
 
-        if parameters.isha_interval > 0 {
-            isha = solar_time
-                .sunset
-                .checked_add_signed(Duration::seconds((parameters.isha_interval * 60) as i64))
-                .unwrap();
+            isha = solar_time.sunset.checked_add_signed(isha_interval).unwrap();
         } else {
-            isha = solar_time.time_for_solar_angle(Angle::new(-parameters.isha_angle), true);
+            let mut angle_isha = solar_time
+                .time_for_solar_angle(Angle::new(-parameters.effective_isha_angle()), true);
 
-            // special case for moonsighting committee above latitude 55
-            if parameters.method == Method::MoonsightingCommittee && coordinates.latitude >= 55.0 {
-                let night_fraction = night.num_seconds() / 7;
-                isha = solar_time
+            // a fraction of the night above latitude 55
+            if parameters.one_seventh_above_55 && coordinates.latitude >= 55.0 {
+                let night_fraction = night.num_seconds() / parameters.high_latitude_divisor as i64;
+                angle_isha = solar_time
                     .sunset
-                    .checked_add_signed(Duration::seconds(night_fraction))
-                    .unwrap();
+                    .checked_add_signed(Duration::seconds(night_fraction));
             } else {
                 // Nothing to do.
             }
@@ -258,87 +1006,452 @@ impl PrayerTimes {
                     .unwrap()
             };
 
-            if isha > safe_isha {
-                isha = safe_isha;
-            } else {
-                // Nothing to do.
-            }
+            // The high latitude rule is also used when the
+            // sun never reaches the angle for Isha.
+            let rule_isha = match angle_isha {
+                Some(angle_isha) if angle_isha <= safe_isha => angle_isha,
+                _ => safe_isha,
+            };
+
+            // A fixed clock time replaces an Isha that is
+            // never reached or is reached after that time.
+            isha = match parameters.isha_fixed_time {
+                Some(fixed_time) => {
+                    let fixed_isha = fixed_time.for_sunset(solar_time.sunset);
+                    let is_later = match angle_isha {
+                        Some(angle_isha) => angle_isha > fixed_isha,
+                        None => true,
+                    };
+
+                    if fixed_isha > solar_time.sunset && is_later {
+                        fixed_isha
+                    } else {
+                        rule_isha
+                    }
+                }
+                None => rule_isha,
+            };
         }
 
-        isha.adjust_time(parameters.time_adjustments(Prayer::Isha))
+        isha.adjust_time(Minutes(parameters.time_adjustments(Prayer::Isha)))
     }
 
     fn calculate_qiyam(
+        current_sunset: DateTime<Utc>,
         current_maghrib: DateTime<Utc>,
+        current_isha: DateTime<Utc>,
         parameters: Parameters,
         solar_time: SolarTime,
         coordinates: Coordinates,
         prayer_date: DateTime<Utc>,
-    ) -> (DateTime<Utc>, DateTime<Utc>, DateTime<Utc>) {
+    ) -> Result<NightTimes, SolarError> {
         let tomorrow = prayer_date.tomorrow();
-        let solar_time_tomorrow = SolarTime::new(tomorrow, coordinates);
+        let solar_time_tomorrow =
+            SolarTime::with_altitude(tomorrow, coordinates, parameters.sunrise_altitude)?;
         let night = solar_time_tomorrow
             .sunrise
             .signed_duration_since(solar_time.sunset);
 
         let tomorrow_fajr =
-            PrayerTimes::calculate_fajr(parameters, solar_time, night, coordinates, prayer_date);
-        let night_duration = tomorrow_fajr
-            .signed_duration_since(current_maghrib)
-            .num_seconds() as f64;
+            PrayerTimes::calculate_fajr(parameters, solar_time, night, coordinates, prayer_date)
+                .rounded_minute(parameters.rounding);
+
+        // The Islamic midnight is the middle of the night,
+        // which starts at Maghrib or at sunset.
+        let (night_begin, night_end) = match parameters.night_definition {
+            NightDefinition::MaghribToFajr => (current_maghrib, tomorrow_fajr),
+            NightDefinition::SunsetToSunrise => (current_sunset, solar_time.sunrise),
+        };
+        let night_duration = night_end.signed_duration_since(night_begin).num_seconds() as f64;
         let middle_night_portion = (night_duration / 2.0) as i64;
-        let last_third_portion = (night_duration * (2.0 / 3.0)) as i64;
-        let middle_of_night = current_maghrib
+        let middle_of_night = night_begin
             .checked_add_signed(Duration::seconds(middle_night_portion))
             .unwrap()
             .rounded_minute(Rounding::Nearest);
-        let last_third_of_night = current_maghrib
+
+        // The last third of the night can be measured
+        // either from Maghrib or from Isha.
+        let night_start = match parameters.qiyam_base {
+            QiyamBase::Maghrib => current_maghrib,
+            QiyamBase::Isha => current_isha,
+        };
+        let qiyam_night_duration = tomorrow_fajr
+            .signed_duration_since(night_start)
+            .num_seconds() as f64;
+        let last_third_portion = (qiyam_night_duration * (2.0 / 3.0)) as i64;
+        let last_third_of_night = night_start
             .checked_add_signed(Duration::seconds(last_third_portion))
             .unwrap()
             .rounded_minute(Rounding::Nearest);
 
-        (middle_of_night, last_third_of_night, tomorrow_fajr)
+        Ok((middle_of_night, last_third_of_night, tomorrow_fajr))
     }
 }
 
-/// A builder for the [PrayerTimes](struct.PrayerTimes.html) struct.
-pub struct PrayerSchedule {
-    date: Option<Date<Utc>>,
-    coordinates: Option<Coordinates>,
-    params: Option<Parameters>,
+/// A timezone-free variant of [PrayerTimes](struct.PrayerTimes.html).
+///
+/// All times are stored as UTC `NaiveDateTime` values, along with the
+/// fixed offset that the observer intends to display them in. This
+/// makes it convenient to store schedules and only localize them when
+/// they are needed.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct NaiveTimes {
+    fajr: NaiveDateTime,
+    sunrise: NaiveDateTime,
+    dhuhr: NaiveDateTime,
+    asr: NaiveDateTime,
+    maghrib: NaiveDateTime,
+    isha: NaiveDateTime,
+    middle_of_the_night: NaiveDateTime,
+    qiyam: NaiveDateTime,
+    fajr_tomorrow: NaiveDateTime,
+    coordinates: Coordinates,
+    date: NaiveDate,
+    parameters: Parameters,
+    offset: FixedOffset,
+    clamped: bool,
 }
 
-impl PrayerSchedule {
-    pub fn new() -> PrayerSchedule {
-        PrayerSchedule {
-            date: None,
-            coordinates: None,
-            params: None,
+impl NaiveTimes {
+    pub fn new(
+        date: NaiveDate,
+        coordinates: Coordinates,
+        parameters: Parameters,
+        offset: FixedOffset,
+    ) -> NaiveTimes {
+        let utc_date = Utc.ymd(date.year(), date.month(), date.day());
+        let times = PrayerTimes::new(utc_date, coordinates, parameters);
+
+        NaiveTimes {
+            fajr: times.fajr.naive_utc(),
+            sunrise: times.sunrise.naive_utc(),
+            dhuhr: times.dhuhr.naive_utc(),
+            asr: times.asr.naive_utc(),
+            maghrib: times.maghrib.naive_utc(),
+            isha: times.isha.naive_utc(),
+            middle_of_the_night: times.middle_of_the_night.naive_utc(),
+            qiyam: times.qiyam.naive_utc(),
+            fajr_tomorrow: times.fajr_tomorrow.naive_utc(),
+            coordinates: coordinates,
+            date: date,
+            parameters: parameters,
+            offset: offset,
+            clamped: times.clamped,
         }
     }
 
-    pub fn on<'a>(&'a mut self, date: Date<Utc>) -> &'a mut PrayerSchedule {
-        self.date = Some(date);
-        self
+    /// Returns the time, in UTC, for the given prayer.
+    pub fn time(&self, prayer: Prayer) -> NaiveDateTime {
+        match prayer {
+            Prayer::Fajr => self.fajr,
+            Prayer::Sunrise => self.sunrise,
+            Prayer::Dhuhr => self.dhuhr,
+            Prayer::Asr => self.asr,
+            Prayer::Maghrib => self.maghrib,
+            Prayer::Isha => self.isha,
+            Prayer::Qiyam => self.qiyam,
+            Prayer::FajrTomorrow => self.fajr_tomorrow,
+        }
     }
 
-    pub fn for_location<'a>(&'a mut self, location: Coordinates) -> &'a mut PrayerSchedule {
-        self.coordinates = Some(location);
-        self
+    /// The fixed offset the observer intends to display the times in.
+    pub fn offset(&self) -> FixedOffset {
+        self.offset
     }
 
-    pub fn with_configuration<'a>(&'a mut self, params: Parameters) -> &'a mut PrayerSchedule {
-        self.params = Some(params);
-        self
+    /// Returns the schedule with all of the times
+    /// expressed in the given offset.
+    pub fn localize(&self, offset: FixedOffset) -> PrayerTimes<FixedOffset> {
+        PrayerTimes {
+            fajr: offset.from_utc_datetime(&self.fajr),
+            sunrise: offset.from_utc_datetime(&self.sunrise),
+            dhuhr: offset.from_utc_datetime(&self.dhuhr),
+            asr: offset.from_utc_datetime(&self.asr),
+            maghrib: offset.from_utc_datetime(&self.maghrib),
+            isha: offset.from_utc_datetime(&self.isha),
+            middle_of_the_night: offset.from_utc_datetime(&self.middle_of_the_night),
+            qiyam: offset.from_utc_datetime(&self.qiyam),
+            fajr_tomorrow: offset.from_utc_datetime(&self.fajr_tomorrow),
+            coordinates: self.coordinates,
+            date: Utc.from_utc_datetime(&self.date.and_hms(0, 0, 0)),
+            parameters: self.parameters,
+            clamped: self.clamped,
+        }
     }
+}
 
-    pub fn calculate(&self) -> Result<PrayerTimes, String> {
-        if self.date.is_some() && self.coordinates.is_some() && self.params.is_some() {
-            Ok(PrayerTimes::new(
-                self.date.unwrap(),
+/// A stateful wrapper for long-running clocks and widgets, that
+/// keeps the [PrayerTimes](struct.PrayerTimes.html) of the current
+/// day, in the timezone of the clock, and only calculates them again
+/// when that day changes.
+#[derive(Debug, Clone)]
+pub struct PrayerClock<Tz: TimeZone = Utc> {
+    coordinates: Coordinates,
+    parameters: Parameters,
+    timezone: Tz,
+    date: Option<Date<Utc>>,
+    times: Option<PrayerTimes<Tz>>,
+}
+
+impl<Tz: TimeZone> PrayerClock<Tz> {
+    pub fn new(coordinates: Coordinates, parameters: Parameters, timezone: &Tz) -> PrayerClock<Tz> {
+        PrayerClock {
+            coordinates: coordinates,
+            parameters: parameters,
+            timezone: timezone.clone(),
+            date: None,
+            times: None,
+        }
+    }
+
+    /// Returns the next prayer after `now`, and its time. The schedule
+    /// is only calculated again when `now` falls on another day than
+    /// the previous tick. Returns `None` when the times can not be
+    /// calculated for the day.
+    pub fn tick(&mut self, now: DateTime<Tz>) -> Option<(Prayer, DateTime<Tz>)> {
+        let date = Utc.from_utc_date(&now.date().naive_local());
+
+        if self.date != Some(date) {
+            self.times = PrayerTimes::try_new(date, self.coordinates, self.parameters)
+                .ok()
+                .map(|times| times.with_timezone(&self.timezone));
+            self.date = Some(date);
+        } else {
+            // Nothing to do.
+        }
+
+        let times = self.times.as_ref()?;
+
+        [
+            Prayer::Fajr,
+            Prayer::Sunrise,
+            Prayer::Dhuhr,
+            Prayer::Asr,
+            Prayer::Maghrib,
+            Prayer::Isha,
+            Prayer::Qiyam,
+            Prayer::FajrTomorrow,
+        ]
+        .iter()
+        .map(|prayer| (*prayer, times.time(*prayer)))
+        .find(|(_, time)| *time > now)
+    }
+}
+
+/// The prayer times of a week, keyed by the day of the week,
+/// e.g. for the weekly timetable of a mosque.
+#[derive(PartialEq, Debug, Clone)]
+pub struct WeeklyTimetable<Tz: TimeZone = Utc> {
+    // Indexed by the number of days from Monday.
+    days: Vec<Option<PrayerTimes<Tz>>>,
+}
+
+impl<Tz: TimeZone> WeeklyTimetable<Tz> {
+    /// Calculates the prayer times for the seven days
+    /// starting at the given Monday, in its timezone.
+    pub fn for_week(
+        monday: DateTime<Tz>,
+        coordinates: Coordinates,
+        parameters: Parameters,
+    ) -> WeeklyTimetable<Tz> {
+        (0..7)
+            .map(|day| {
+                let date = (monday.clone() + Duration::days(day)).date().naive_local();
+
+                PrayerTimes::new(Utc.from_utc_date(&date), coordinates, parameters)
+                    .with_timezone(&monday.timezone())
+            })
+            .collect()
+    }
+
+    pub fn get(&self, weekday: Weekday) -> Option<&PrayerTimes<Tz>> {
+        self.days[weekday.num_days_from_monday() as usize].as_ref()
+    }
+}
+
+impl<Tz: TimeZone> Default for WeeklyTimetable<Tz> {
+    fn default() -> Self {
+        WeeklyTimetable {
+            days: vec![None; 7],
+        }
+    }
+}
+
+impl<Tz: TimeZone> Extend<PrayerTimes<Tz>> for WeeklyTimetable<Tz> {
+    /// Adds the prayer times on the day of the week of their date,
+    /// replacing the times that are already there for that day.
+    fn extend<I: IntoIterator<Item = PrayerTimes<Tz>>>(&mut self, iter: I) {
+        for times in iter {
+            let index = times.date.weekday().num_days_from_monday() as usize;
+            self.days[index] = Some(times);
+        }
+    }
+}
+
+impl<Tz: TimeZone> FromIterator<PrayerTimes<Tz>> for WeeklyTimetable<Tz> {
+    fn from_iter<I: IntoIterator<Item = PrayerTimes<Tz>>>(iter: I) -> Self {
+        let mut timetable = WeeklyTimetable::default();
+        timetable.extend(iter);
+        timetable
+    }
+}
+
+impl<Tz: TimeZone> fmt::Display for WeeklyTimetable<Tz>
+where
+    Tz::Offset: fmt::Display,
+{
+    /// Writes one row per day, from Monday to Sunday, with the
+    /// name and time (`HH:MM`) of each prayer on that day.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let prayers = [
+            Prayer::Fajr,
+            Prayer::Sunrise,
+            Prayer::Dhuhr,
+            Prayer::Asr,
+            Prayer::Maghrib,
+            Prayer::Isha,
+        ];
+
+        for times in self.days.iter().flatten() {
+            let weekday = times.date.weekday();
+            write!(f, "{:?}", weekday)?;
+
+            for prayer in prayers.iter() {
+                write!(
+                    f,
+                    " | {} {}",
+                    prayer.name_on(weekday),
+                    times.time(*prayer).format("%H:%M")
+                )?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A builder for the [PrayerTimes](struct.PrayerTimes.html) struct.
+pub struct PrayerSchedule {
+    date: Option<Date<Utc>>,
+    invalid_timestamp: Option<i64>,
+    coordinates: Option<Coordinates>,
+    params: Option<Parameters>,
+}
+
+impl PrayerSchedule {
+    pub fn new() -> PrayerSchedule {
+        PrayerSchedule {
+            date: None,
+            invalid_timestamp: None,
+            coordinates: None,
+            params: None,
+        }
+    }
+
+    pub fn on<'a>(&'a mut self, date: Date<Utc>) -> &'a mut PrayerSchedule {
+        self.date = Some(date);
+        self.invalid_timestamp = None;
+        self
+    }
+
+    /// Uses the UTC date of the given UNIX timestamp (in seconds).
+    /// A timestamp that is out of range is reported by `calculate()`.
+    pub fn with_timestamp<'a>(&'a mut self, epoch_secs: i64) -> &'a mut PrayerSchedule {
+        match Utc.timestamp_opt(epoch_secs, 0).single() {
+            Some(time) => {
+                self.date = Some(time.date());
+                self.invalid_timestamp = None;
+            }
+            None => {
+                self.date = None;
+                self.invalid_timestamp = Some(epoch_secs);
+            }
+        }
+        self
+    }
+
+    /// Uses the Gregorian date of the given date in the
+    /// tabular Islamic calendar.
+    pub fn with_hijri_date<'a>(&'a mut self, hijri: Hijri) -> &'a mut PrayerSchedule {
+        self.date = Some(Date::from_utc(hijri.to_gregorian(), Utc));
+        self.invalid_timestamp = None;
+        self
+    }
+
+    pub fn for_location<'a>(&'a mut self, location: Coordinates) -> &'a mut PrayerSchedule {
+        self.coordinates = Some(location);
+        self
+    }
+
+    /// Uses the location at the given latitude
+    /// and longitude (in degrees).
+    pub fn with_lat_lon<'a>(&'a mut self, latitude: f64, longitude: f64) -> &'a mut PrayerSchedule {
+        self.coordinates = Some(Coordinates::new(latitude, longitude));
+        self
+    }
+
+    pub fn with_configuration<'a>(&'a mut self, params: Parameters) -> &'a mut PrayerSchedule {
+        self.params = Some(params);
+        self
+    }
+
+    /// Uses the parameters provided by the given method,
+    /// which can be one of the presets or a custom method.
+    pub fn with_method<'a, M: CalculationMethod>(
+        &'a mut self,
+        method: M,
+    ) -> &'a mut PrayerSchedule {
+        self.params = Some(method.parameters());
+        self
+    }
+
+    /// Calculates today's (UTC) prayer times from command line style
+    /// arguments, e.g. `from_args("ISNA", "Hanafi", 40.7128, -74.0059)`.
+    /// The method and madhab are parsed with their `FromStr` impls.
+    pub fn from_args(
+        method: &str,
+        madhab: &str,
+        latitude: f64,
+        longitude: f64,
+    ) -> Result<PrayerTimes, String> {
+        PrayerSchedule::from_args_on(Utc::now().date(), method, madhab, latitude, longitude)
+    }
+
+    fn from_args_on(
+        date: Date<Utc>,
+        method: &str,
+        madhab: &str,
+        latitude: f64,
+        longitude: f64,
+    ) -> Result<PrayerTimes, String> {
+        let method = method.parse::<Method>()?;
+        let madhab = madhab.parse::<Madhab>()?;
+
+        if !(-90.0..=90.0).contains(&latitude) {
+            Err(format!("Latitude {} is out of range.", latitude))
+        } else if !(-180.0..=180.0).contains(&longitude) {
+            Err(format!("Longitude {} is out of range.", longitude))
+        } else {
+            PrayerSchedule::new()
+                .on(date)
+                .with_lat_lon(latitude, longitude)
+                .with_configuration(Configuration::with(method, madhab))
+                .calculate()
+        }
+    }
+
+    pub fn calculate(&self) -> Result<PrayerTimes, String> {
+        if let Some(epoch_secs) = self.invalid_timestamp {
+            Err(format!("The timestamp {} is out of range.", epoch_secs))
+        } else if self.date.is_some() && self.coordinates.is_some() && self.params.is_some() {
+            PrayerTimes::try_new(
+                self.date.unwrap(),
                 self.coordinates.unwrap(),
                 self.params.unwrap(),
-            ))
+            )
+            .map_err(|error| error.to_string())
         } else {
             Err(String::from(
                 "Required information is needed in order to calculate the prayer times.",
@@ -347,12 +1460,76 @@ impl PrayerSchedule {
     }
 }
 
+/// Calculates the prayer times for the UTC date
+/// of the given UNIX timestamp (in seconds).
+///
+/// Returns an error when the timestamp is out of range, or when the
+/// sun does not rise or does not set around that date.
+pub fn times_from_timestamp(
+    epoch_secs: i64,
+    coordinates: Coordinates,
+    parameters: Parameters,
+) -> Result<PrayerTimes, String> {
+    PrayerSchedule::new()
+        .with_timestamp(epoch_secs)
+        .for_location(coordinates)
+        .with_configuration(parameters)
+        .calculate()
+}
+
+/// Calculates the prayer times for every day of the given month and
+/// returns them as a JSON array of `{date, fajr, ..., isha}` objects,
+/// with the date as `YYYY-MM-DD` and the times in RFC 3339 (UTC).
+///
+/// Returns an error when the month is invalid, or when the sun does
+/// not rise or does not set around one of the days.
+pub fn month_json(
+    year: i32,
+    month: u32,
+    coordinates: Coordinates,
+    parameters: Parameters,
+) -> Result<String, String> {
+    let prayers = [
+        ("fajr", Prayer::Fajr),
+        ("sunrise", Prayer::Sunrise),
+        ("dhuhr", Prayer::Dhuhr),
+        ("asr", Prayer::Asr),
+        ("maghrib", Prayer::Maghrib),
+        ("isha", Prayer::Isha),
+    ];
+    let mut days = Vec::new();
+    let mut date = Utc
+        .ymd_opt(year, month, 1)
+        .single()
+        .ok_or_else(|| format!("The month {}-{:02} is invalid.", year, month))?;
+
+    while date.month() == month {
+        let times = PrayerTimes::try_new(date, coordinates, parameters)
+            .map_err(|error| error.to_string())?;
+        let mut day = format!("{{\"date\":\"{}\"", date.format("%Y-%m-%d"));
+
+        for (key, prayer) in prayers.iter() {
+            day.push_str(&format!(
+                ",\"{}\":\"{}\"",
+                key,
+                times.time(*prayer).to_rfc3339()
+            ));
+        }
+
+        day.push('}');
+        days.push(day);
+        date = date.succ();
+    }
+
+    Ok(format!("[{}]", days.join(",")))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::madhab::Madhab;
     use crate::Configuration;
-    use chrono::{TimeZone, Utc};
+    use crate::{Adjustment, HighLatitudeRule, Shafaq, TimeAdjustment};
+    use chrono::{NaiveTime, TimeZone, Utc};
 
     #[test]
     fn current_prayer_should_be_fajr() {
@@ -498,6 +1675,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn calculate_times_from_args() {
+        let date = Utc.ymd(2015, 7, 12);
+        let result = PrayerSchedule::from_args_on(date, "ISNA", "Hanafi", 40.7128, -74.0059);
+        let expected = PrayerTimes::new(
+            date,
+            Coordinates::new(40.7128, -74.0059),
+            Configuration::with(Method::NorthAmerica, Madhab::Hanafi),
+        );
+
+        assert_eq!(result, Ok(expected));
+        assert!(PrayerSchedule::from_args("ISNA", "Hanafi", 40.7128, -74.0059).is_ok());
+        assert!(PrayerSchedule::from_args("Makkah", "Hanafi", 40.7128, -74.0059).is_err());
+        assert!(PrayerSchedule::from_args("ISNA", "Maliki", 40.7128, -74.0059).is_err());
+        assert!(PrayerSchedule::from_args("ISNA", "Hanafi", 140.0, -74.0059).is_err());
+    }
+
     #[test]
     fn calculate_times_for_moonsighting_method_with_high_latitude() {
         let date = Utc.ymd(2016, 1, 1);
@@ -552,4 +1746,1351 @@ mod tests {
             Err(_err) => assert!(false),
         }
     }
+
+    #[test]
+    fn calculate_times_with_safety_margin() {
+        let date = Utc.ymd(2021, 1, 12);
+        let coordinates = Coordinates::new(-6.18233995, 106.84287154);
+        let params = Configuration::new(20.0, 18.0).done();
+        let params_with_margin = Configuration::new(20.0, 18.0).safety_margin(2).done();
+        let times = PrayerTimes::new(date, coordinates, params);
+        let times_with_margin = PrayerTimes::new(date, coordinates, params_with_margin);
+        let margin = |prayer: Prayer| {
+            times_with_margin
+                .time(prayer)
+                .signed_duration_since(times.time(prayer))
+                .num_minutes()
+        };
+
+        assert_eq!(margin(Prayer::Fajr), 2);
+        assert_eq!(margin(Prayer::Sunrise), -2);
+        assert_eq!(margin(Prayer::Dhuhr), 2);
+        assert_eq!(margin(Prayer::Asr), 2);
+        assert_eq!(margin(Prayer::Maghrib), 2);
+        assert_eq!(margin(Prayer::Isha), 2);
+    }
+
+    #[test]
+    fn calculate_times_through_naive_times() {
+        let date = Utc.ymd(2021, 1, 13);
+        let params = Configuration::with(Method::Singapore, Madhab::Shafi);
+        let coordinates = Coordinates::new(1.370844612058886, 103.80145644060552);
+        let sgt_offset = FixedOffset::east(8 * 3600);
+        let expected = PrayerTimes::new(date, coordinates, params).with_timezone(&sgt_offset);
+        let naive = NaiveTimes::new(date.naive_utc(), coordinates, params, sgt_offset);
+        let localized = naive.localize(naive.offset());
+
+        assert_eq!(localized, expected);
+        assert_eq!(naive.time(Prayer::Fajr), expected.time(Prayer::Fajr).naive_utc());
+        assert_eq!(
+            localized.time(Prayer::Fajr).format("%-l:%M %p").to_string(),
+            "5:50 AM"
+        );
+    }
+
+    #[test]
+    fn twilight_status_at_the_equator() {
+        let date = Utc.ymd(2021, 6, 21);
+        let params = Configuration::with(Method::MuslimWorldLeague, Madhab::Shafi);
+        let coordinates = Coordinates::new(0.0, 32.58);
+        let times = PrayerTimes::new(date, coordinates, params);
+
+        assert_eq!(times.twilight_status(), TwilightStatus::Occurs);
+    }
+
+    #[test]
+    fn twilight_status_with_shafaq_and_isha_interval() {
+        let date = Utc.ymd(2021, 6, 21);
+        let coordinates = Coordinates::new(50.0, 8.68);
+        let params = Configuration::new(15.0, 15.0).done();
+        let mut abyad_params = params;
+        abyad_params.shafaq = Shafaq::Abyad;
+        let mut interval_params = abyad_params;
+        interval_params.isha_interval = 90;
+
+        assert_eq!(
+            PrayerTimes::new(date, coordinates, params).twilight_status(),
+            TwilightStatus::Occurs
+        );
+        assert_eq!(
+            PrayerTimes::new(date, coordinates, abyad_params).twilight_status(),
+            TwilightStatus::PersistentTwilight
+        );
+        assert_eq!(
+            PrayerTimes::new(date, coordinates, interval_params).twilight_status(),
+            TwilightStatus::Occurs
+        );
+    }
+
+    #[test]
+    fn format_times_as_markdown() {
+        let date = Utc.ymd(2015, 7, 12);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let times = PrayerTimes::new(date, coordinates, params);
+        let markdown = times.to_markdown();
+        let lines: Vec<&str> = markdown.lines().collect();
+
+        assert!(markdown.starts_with("| Fajr |"));
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[1], "| --- | --- | --- | --- | --- | --- |");
+        assert!(lines[2].starts_with("| 08:42 | 10:08 |"));
+        assert!(lines.iter().all(|line| line.matches('|').count() == 7));
+    }
+
+    #[test]
+    fn adjust_a_single_prayer() {
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let times = PrayerTimes::new(Utc.ymd(2015, 7, 12), coordinates, params);
+        let adjusted = times.with_prayer_adjustment(Prayer::Isha, 5);
+
+        assert_eq!(
+            adjusted.time(Prayer::Isha),
+            times.time(Prayer::Isha) + Duration::minutes(5)
+        );
+
+        for prayer in [
+            Prayer::Fajr,
+            Prayer::Sunrise,
+            Prayer::Dhuhr,
+            Prayer::Asr,
+            Prayer::Maghrib,
+            Prayer::Qiyam,
+            Prayer::FajrTomorrow,
+        ] {
+            assert_eq!(adjusted.time(prayer), times.time(prayer));
+        }
+    }
+
+    #[test]
+    fn interpolate_between_two_days() {
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let today = PrayerTimes::new(Utc.ymd(2015, 7, 12), coordinates, params);
+        let tomorrow = today.tomorrow();
+        let halfway = today.lerp(&tomorrow, 0.5);
+
+        assert_eq!(today.lerp(&tomorrow, 0.0), today);
+        assert_eq!(today.lerp(&tomorrow, 1.0), tomorrow);
+
+        for prayer in [
+            Prayer::Fajr,
+            Prayer::Dhuhr,
+            Prayer::Isha,
+            Prayer::FajrTomorrow,
+        ] {
+            let span = tomorrow.time(prayer) - today.time(prayer);
+
+            assert_eq!(halfway.time(prayer), today.time(prayer) + span / 2);
+        }
+    }
+
+    #[test]
+    fn daily_prayers_of_the_schedule() {
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let times = PrayerTimes::new(Utc.ymd(2015, 7, 12), coordinates, params);
+        let daily = times.daily_prayers();
+
+        assert_eq!(daily.len(), 5);
+
+        for ((prayer, time), expected) in daily.iter().zip(Prayer::all().iter()) {
+            assert_eq!(prayer, expected);
+            assert_eq!(**time, times.time(*prayer));
+        }
+    }
+
+    #[test]
+    fn jumuah_day_for_a_friday() {
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let sunday = PrayerTimes::new(Utc.ymd(2015, 7, 12), coordinates, params);
+        let friday = PrayerTimes::new(Utc.ymd(2015, 7, 17), coordinates, params);
+
+        assert!(!sunday.is_jumuah_day());
+        assert!(friday.is_jumuah_day());
+        assert!(sunday.to_markdown().contains("| Dhuhr |"));
+        assert!(friday.to_markdown().contains("| Jumua |"));
+    }
+
+    #[test]
+    fn calculate_qiyam_from_isha() {
+        let date = Utc.ymd(2015, 7, 12);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let mut isha_params = params;
+        isha_params.qiyam_base = QiyamBase::Isha;
+
+        let times = PrayerTimes::new(date, coordinates, params);
+        let isha_times = PrayerTimes::new(date, coordinates, isha_params);
+        let night = times
+            .time(Prayer::FajrTomorrow)
+            .signed_duration_since(times.time(Prayer::Isha));
+        let expected = times.time(Prayer::Isha) + Duration::seconds(night.num_seconds() * 2 / 3);
+
+        assert!(isha_times.time(Prayer::Qiyam) > times.time(Prayer::Qiyam));
+        assert_eq!(
+            isha_times.time(Prayer::Qiyam),
+            expected.rounded_minute(Rounding::Nearest)
+        );
+        assert_eq!(isha_times.time(Prayer::Isha), times.time(Prayer::Isha));
+    }
+
+    #[test]
+    fn previous_prayer_should_be_isha_from_yesterday() {
+        let date = Utc.ymd(2015, 7, 12);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let times = PrayerTimes::new(date, coordinates, params);
+        let yesterday = PrayerTimes::new(Utc.ymd(2015, 7, 11), coordinates, params);
+        let (prayer, time) = times.previous_prayer(date.and_hms(3, 0, 0)).unwrap();
+
+        assert_eq!(prayer, Prayer::Isha);
+        assert_eq!(time, yesterday.time(Prayer::Isha));
+    }
+
+    #[test]
+    fn previous_prayer_after_the_polar_night() {
+        // The sun rises again on January 17th, after the polar night.
+        let date = Utc.ymd(2021, 1, 17);
+        let params = Configuration::with(Method::MuslimWorldLeague, Madhab::Shafi);
+        let coordinates = Coordinates::new(70.0, 19.0);
+        let times = PrayerTimes::new(date, coordinates, params);
+
+        assert_eq!(times.previous_prayer(date.and_hms(0, 0, 0)), None);
+        assert_eq!(
+            times.previous_prayer(times.time(Prayer::Dhuhr)),
+            Some((Prayer::Dhuhr, times.time(Prayer::Dhuhr)))
+        );
+    }
+
+    #[test]
+    fn previous_prayer_should_be_dhuhr() {
+        let date = Utc.ymd(2015, 7, 12);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let times = PrayerTimes::new(date, coordinates, params);
+        let (prayer, time) = times.previous_prayer(date.and_hms(19, 0, 0)).unwrap();
+
+        assert_eq!(prayer, Prayer::Dhuhr);
+        assert_eq!(time, times.time(Prayer::Dhuhr));
+    }
+
+    #[test]
+    fn calculate_fajr_with_adaptive_angle() {
+        let date = Utc.ymd(2021, 3, 1);
+        let mut params = Configuration::with(Method::MuslimWorldLeague, Madhab::Shafi);
+        let fixed_params = params;
+        params.adaptive_fajr = true;
+
+        // Below 48° the adaptive angle is the same as the
+        // standard 18° angle used by the Muslim World League.
+        let madrid = Coordinates::new(40.4168, -3.7038);
+        let adaptive = PrayerTimes::new(date, madrid, params);
+        let fixed = PrayerTimes::new(date, madrid, fixed_params);
+
+        assert_eq!(adaptive.time(Prayer::Fajr), fixed.time(Prayer::Fajr));
+
+        // Above 60° a shallower angle gives a later Fajr.
+        let oslo = Coordinates::new(60.0, 10.7522);
+        let adaptive = PrayerTimes::new(date, oslo, params);
+        let fixed = PrayerTimes::new(date, oslo, fixed_params);
+
+        assert!(adaptive.time(Prayer::Fajr) > fixed.time(Prayer::Fajr));
+    }
+
+    #[test]
+    fn convert_times_to_utc_and_local() {
+        let date = Utc.ymd(2021, 1, 13);
+        let params = Configuration::with(Method::Singapore, Madhab::Shafi);
+        let coordinates = Coordinates::new(1.370844612058886, 103.80145644060552);
+        let times = PrayerTimes::new(date, coordinates, params);
+        let sgt_times = times.with_timezone(&FixedOffset::east(8 * 3600));
+        let utc_times = sgt_times.as_utc();
+        let local_times = sgt_times.as_local();
+
+        assert_eq!(utc_times, times);
+        assert_eq!(utc_times.time(Prayer::Fajr), sgt_times.time(Prayer::Fajr));
+        assert_eq!(local_times.time(Prayer::Isha), sgt_times.time(Prayer::Isha));
+    }
+
+    #[test]
+    fn restriction_before_transit_should_be_zawal() {
+        let date = Utc.ymd(2015, 7, 12);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let schedule = PrayerSchedule::new()
+            .on(date)
+            .for_location(coordinates)
+            .with_configuration(params)
+            .calculate()
+            .unwrap();
+        let solar_time = SolarTime::new(date.and_hms(0, 0, 0), coordinates).unwrap();
+
+        assert_eq!(
+            schedule.restriction(solar_time.transit - Duration::minutes(1)),
+            Some(Reason::DuringZawal)
+        );
+        assert_eq!(
+            schedule.restriction(solar_time.sunrise + Duration::minutes(1)),
+            Some(Reason::DuringSunrise)
+        );
+        assert_eq!(
+            schedule.restriction(solar_time.sunset - Duration::minutes(1)),
+            Some(Reason::DuringSunset)
+        );
+        assert_eq!(
+            schedule.restriction(solar_time.transit - Duration::minutes(6)),
+            None
+        );
+    }
+
+    #[test]
+    fn prayer_times_as_fractions_of_the_day() {
+        let date = Utc.ymd(2015, 7, 12);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let times = PrayerTimes::new(date, coordinates, params);
+        let fractions = times
+            .with_timezone(&FixedOffset::west(4 * 3600))
+            .as_fractions();
+
+        assert_eq!(fractions[0].0, Prayer::Fajr);
+        assert_eq!(fractions[5].0, Prayer::Isha);
+        assert!(fractions[0].1 < fractions[5].1);
+
+        for (_, fraction) in fractions.iter() {
+            assert!(*fraction >= 0.0 && *fraction < 1.0);
+        }
+    }
+
+    #[test]
+    fn round_times_after_timezone_conversion() {
+        let date = Utc.ymd(2021, 1, 13);
+        let kathmandu = Coordinates::new(27.7172, 85.3240);
+        let mut params = Configuration::with(Method::Karachi, Madhab::Hanafi);
+        params.rounding = Rounding::None;
+        let times = PrayerTimes::new(date, kathmandu, params);
+        let npt_offset = FixedOffset::east(5 * 3600 + 45 * 60);
+        let npt_times = times.rounded_in(&npt_offset, Rounding::Nearest);
+
+        for prayer in [Prayer::Fajr, Prayer::Dhuhr, Prayer::Isha].iter() {
+            let local_time = npt_times.time(*prayer);
+
+            assert_eq!(local_time.second(), 0);
+            assert_eq!(local_time.offset(), &npt_offset);
+            assert!((local_time.timestamp() - times.time(*prayer).timestamp()).abs() <= 30);
+        }
+    }
+
+    #[test]
+    fn calculate_times_with_custom_method() {
+        struct LocalCommittee;
+
+        impl CalculationMethod for LocalCommittee {
+            fn parameters(&self) -> Parameters {
+                Configuration::new(16.0, 14.0).done()
+            }
+        }
+
+        let date = Utc.ymd(2015, 7, 12);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let custom = PrayerSchedule::new()
+            .on(date)
+            .for_location(coordinates)
+            .with_method(LocalCommittee)
+            .calculate()
+            .unwrap();
+        let expected = PrayerTimes::new(date, coordinates, Configuration::new(16.0, 14.0).done());
+        let preset = PrayerSchedule::new()
+            .on(date)
+            .for_location(coordinates)
+            .with_method(Method::MuslimWorldLeague)
+            .calculate()
+            .unwrap();
+
+        assert_eq!(custom, expected);
+        assert_eq!(
+            preset,
+            PrayerTimes::new(date, coordinates, Method::MuslimWorldLeague.parameters())
+        );
+    }
+
+    #[test]
+    fn coordinates_used_for_the_times() {
+        let date = Utc.ymd(2015, 7, 12);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let times = PrayerTimes::new(date, coordinates, params);
+
+        assert_eq!(times.coordinates(), &coordinates);
+        assert_eq!(times.as_local().coordinates(), &coordinates);
+    }
+
+    #[test]
+    fn time_after_maghrib_is_on_the_next_islamic_day() {
+        let date = Utc.ymd(2015, 7, 12);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let times = PrayerTimes::new(date, coordinates, params);
+        let boundary = *times.islamic_date_boundary();
+
+        assert_eq!(boundary, times.time(Prayer::Maghrib));
+        assert!(times.is_after_islamic_midnight(boundary + Duration::minutes(1)));
+        assert!(!times.is_after_islamic_midnight(boundary - Duration::minutes(1)));
+    }
+
+    #[test]
+    fn calculate_times_for_both_madhabs() {
+        let date = Utc.ymd(2015, 7, 12);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let (shafi, hanafi) = PrayerTimes::both_madhabs(date, coordinates, params);
+
+        for prayer in [
+            Prayer::Fajr,
+            Prayer::Sunrise,
+            Prayer::Dhuhr,
+            Prayer::Maghrib,
+            Prayer::Isha,
+        ]
+        .iter()
+        {
+            assert_eq!(shafi.time(*prayer), hanafi.time(*prayer));
+        }
+
+        assert!(shafi.time(Prayer::Asr) < hanafi.time(Prayer::Asr));
+        assert_eq!(hanafi, PrayerTimes::new(date, coordinates, params));
+    }
+
+    #[test]
+    fn calculate_isha_for_umm_al_qura_in_ramadan() {
+        let makkah = Coordinates::makkah();
+        let ramadan = Utc.ymd(2021, 4, 20);
+        let shawwal = Utc.ymd(2021, 5, 20);
+        let mut params = Configuration::with(Method::UmmAlQura, Madhab::Shafi);
+        params.ramadan_isha_extension = true;
+        let ramadan_times = PrayerTimes::new(ramadan, makkah, params);
+        let shawwal_times = PrayerTimes::new(shawwal, makkah, params);
+
+        assert_eq!(
+            ramadan_times
+                .time(Prayer::Isha)
+                .signed_duration_since(ramadan_times.time(Prayer::Maghrib)),
+            Duration::minutes(120)
+        );
+        assert_eq!(
+            shawwal_times
+                .time(Prayer::Isha)
+                .signed_duration_since(shawwal_times.time(Prayer::Maghrib)),
+            Duration::minutes(90)
+        );
+    }
+
+    #[test]
+    fn calculate_times_from_timestamp() {
+        let timestamp = 1436705940;
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let expected = PrayerTimes::new(Utc.ymd(2015, 7, 12), coordinates, params);
+        let schedule = PrayerSchedule::new()
+            .with_timestamp(timestamp)
+            .for_location(coordinates)
+            .with_configuration(params)
+            .calculate()
+            .unwrap();
+
+        assert_eq!(schedule, expected);
+        assert_eq!(
+            times_from_timestamp(timestamp, coordinates, params),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn calculate_times_from_out_of_range_timestamp() {
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let result = PrayerSchedule::new()
+            .with_timestamp(i64::MAX)
+            .for_location(coordinates)
+            .with_configuration(params)
+            .calculate();
+
+        assert!(result.is_err());
+        assert!(times_from_timestamp(i64::MIN, coordinates, params).is_err());
+        assert!(PrayerSchedule::new()
+            .with_timestamp(i64::MAX)
+            .on(Utc.ymd(2015, 7, 12))
+            .for_location(coordinates)
+            .with_configuration(params)
+            .calculate()
+            .is_ok());
+    }
+
+    #[test]
+    fn difference_between_methods() {
+        let date = Utc.ymd(2015, 7, 12);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let karachi = PrayerTimes::new(
+            date,
+            coordinates,
+            Configuration::with(Method::Karachi, Madhab::Hanafi),
+        );
+        let north_america = PrayerTimes::new(
+            date,
+            coordinates,
+            Configuration::with(Method::NorthAmerica, Madhab::Hanafi),
+        );
+        let differences = karachi.diff(&north_america);
+
+        assert_eq!(differences[0].0, Prayer::Fajr);
+        assert!(differences[0].1 < Duration::zero());
+        assert_eq!(differences[2], (Prayer::Dhuhr, Duration::zero()));
+        assert!(differences[5].1 > Duration::zero());
+    }
+
+    #[test]
+    fn calculate_times_with_latitude_and_longitude() {
+        let date = Utc.ymd(2015, 7, 12);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let with_lat_lon = PrayerSchedule::new()
+            .on(date)
+            .with_lat_lon(35.7750, -78.6336)
+            .with_configuration(params)
+            .calculate();
+        let with_coordinates = PrayerSchedule::new()
+            .on(date)
+            .for_location(Coordinates::new(35.7750, -78.6336))
+            .with_configuration(params)
+            .calculate();
+
+        assert_eq!(with_lat_lon, with_coordinates);
+    }
+
+    #[test]
+    fn calculate_times_without_sunset() {
+        let date = Utc.ymd(2021, 6, 21);
+        let svalbard = Coordinates::new(80.0, 16.0);
+        let params = Configuration::with(Method::MuslimWorldLeague, Madhab::Shafi);
+        let schedule = PrayerSchedule::new()
+            .on(date)
+            .for_location(svalbard)
+            .with_configuration(params)
+            .calculate();
+
+        assert_eq!(
+            PrayerTimes::try_new(date, svalbard, params),
+            Err(SolarError::MidnightSun)
+        );
+        assert_eq!(
+            schedule,
+            Err(String::from("The sun does not set on the given day."))
+        );
+    }
+
+    #[test]
+    fn calculate_dhuhr_with_delay_after_transit() {
+        let date = Utc.ymd(2015, 7, 12);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::new(18.0, 17.0).dhuhr_delay(3).done();
+        let times = PrayerTimes::new(date, coordinates, params);
+        let solar_time = SolarTime::new(date.and_hms(0, 0, 0), coordinates).unwrap();
+
+        assert_eq!(
+            times.time(Prayer::Dhuhr),
+            (solar_time.transit + Duration::minutes(3)).rounded_minute(Rounding::Nearest)
+        );
+    }
+
+    #[test]
+    fn calculate_times_halfway_between_cities() {
+        let date = Utc.ymd(2021, 3, 1);
+        let new_york = Coordinates::new(40.7128, -74.0059);
+        let denver = Coordinates::new(39.7392, -104.9903);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let start = PrayerTimes::new(date, new_york, params);
+        let end = PrayerTimes::new(date, denver, params);
+        let halfway = PrayerTimes::at_fraction(new_york, denver, 0.5, date, params).unwrap();
+
+        for prayer in [Prayer::Dhuhr, Prayer::Asr, Prayer::Maghrib].iter() {
+            assert!(start.time(*prayer) < halfway.time(*prayer));
+            assert!(halfway.time(*prayer) < end.time(*prayer));
+        }
+    }
+
+    #[test]
+    fn calculate_times_when_twilight_angle_is_not_reached() {
+        let date = Utc.ymd(2021, 6, 21);
+        let oslo = Coordinates::new(59.9139, 10.7522);
+        let params = Configuration::with(Method::MuslimWorldLeague, Madhab::Shafi);
+        let times = PrayerTimes::new(date, oslo, params);
+        let solar_time = SolarTime::new(date.and_hms(0, 0, 0), oslo).unwrap();
+        let night = SolarTime::new(date.and_hms(0, 0, 0).tomorrow(), oslo)
+            .unwrap()
+            .sunrise
+            .signed_duration_since(solar_time.sunset);
+
+        assert_eq!(times.twilight_status(), TwilightStatus::PersistentTwilight);
+        assert_eq!(
+            times.time(Prayer::Fajr),
+            (solar_time.sunrise - Duration::seconds(night.num_seconds() / 2))
+                .rounded_minute(Rounding::Nearest)
+        );
+        assert_eq!(
+            times.time(Prayer::Isha),
+            (solar_time.sunset + Duration::seconds(night.num_seconds() / 2))
+                .rounded_minute(Rounding::Nearest)
+        );
+    }
+
+    #[test]
+    fn calculate_isha_with_shafaq_for_other_methods() {
+        let date = Utc.ymd(2015, 7, 12);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let general = Configuration::new(18.0, 17.0).done();
+        let ahmer = Configuration::new(18.0, 17.0).shafaq(Shafaq::Ahmer).done();
+        let abyad = Configuration::new(18.0, 17.0).shafaq(Shafaq::Abyad).done();
+        let general_isha = PrayerTimes::new(date, coordinates, general).time(Prayer::Isha);
+        let ahmer_isha = PrayerTimes::new(date, coordinates, ahmer).time(Prayer::Isha);
+        let abyad_isha = PrayerTimes::new(date, coordinates, abyad).time(Prayer::Isha);
+        let red_twilight = Configuration::new(18.0, 14.0).done();
+
+        assert_eq!(ahmer.effective_isha_angle(), 14.0);
+        assert_eq!(abyad.effective_isha_angle(), 20.0);
+        assert!(ahmer_isha < general_isha);
+        assert!(abyad_isha > general_isha);
+        assert_eq!(
+            ahmer_isha,
+            PrayerTimes::new(date, coordinates, red_twilight).time(Prayer::Isha)
+        );
+    }
+
+    #[test]
+    fn calculate_times_with_dubai_angles_and_no_offsets() {
+        let date = Utc.ymd(2021, 3, 1);
+        let dubai = Coordinates::new(25.2048, 55.2708);
+        let angles_only = Configuration::new(18.2, 18.2).done();
+        let with_offsets = Configuration::new(18.2, 18.2)
+            .method_adjustments(Adjustment::dubai().done())
+            .done();
+        let plain = PrayerTimes::new(date, dubai, angles_only);
+        let adjusted = PrayerTimes::new(date, dubai, with_offsets);
+        let method = PrayerTimes::new(date, dubai, Method::Dubai.parameters());
+
+        for prayer in [
+            Prayer::Fajr,
+            Prayer::Sunrise,
+            Prayer::Dhuhr,
+            Prayer::Asr,
+            Prayer::Maghrib,
+            Prayer::Isha,
+        ] {
+            assert_eq!(adjusted.time(prayer), method.time(prayer));
+        }
+        assert_eq!(plain.time(Prayer::Fajr), method.time(Prayer::Fajr));
+        assert_eq!(
+            plain.time(Prayer::Sunrise) - Duration::minutes(3),
+            method.time(Prayer::Sunrise)
+        );
+        assert_eq!(
+            plain.time(Prayer::Dhuhr) + Duration::minutes(3),
+            method.time(Prayer::Dhuhr)
+        );
+        assert_eq!(
+            plain.time(Prayer::Asr) + Duration::minutes(3),
+            method.time(Prayer::Asr)
+        );
+        assert_eq!(
+            plain.time(Prayer::Maghrib) + Duration::minutes(3),
+            method.time(Prayer::Maghrib)
+        );
+        assert_eq!(plain.time(Prayer::Isha), method.time(Prayer::Isha));
+    }
+
+    #[test]
+    fn fraction_of_the_night_elapsed() {
+        let date = Utc.ymd(2015, 7, 12);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let times = PrayerTimes::new(date, coordinates, params);
+        let middle = times.night_fraction(&times.middle_of_the_night).unwrap();
+
+        assert_eq!(
+            times.night_fraction(&times.time(Prayer::Maghrib)),
+            Some(0.0)
+        );
+        assert_eq!(
+            times.night_fraction(&times.time(Prayer::FajrTomorrow)),
+            Some(1.0)
+        );
+        assert!((middle - 0.5).abs() < 0.002);
+        assert_eq!(times.night_fraction(&times.time(Prayer::Dhuhr)), None);
+    }
+
+    #[test]
+    fn calculate_times_with_angle_proportional_rule() {
+        let date = Utc.ymd(2021, 6, 21);
+        let coordinates = Coordinates::new(58.0, 10.0);
+        let proportional = Configuration::new(18.0, 17.0)
+            .high_latitude_rule(HighLatitudeRule::AngleProportional)
+            .done();
+        let seventh = Configuration::new(18.0, 17.0)
+            .high_latitude_rule(HighLatitudeRule::SeventhOfTheNight)
+            .done();
+        let proportional_times = PrayerTimes::new(date, coordinates, proportional);
+        let seventh_times = PrayerTimes::new(date, coordinates, seventh);
+
+        assert!(proportional_times.time(Prayer::Fajr) < seventh_times.time(Prayer::Fajr));
+        assert!(proportional_times.time(Prayer::Isha) > seventh_times.time(Prayer::Isha));
+        assert!(
+            proportional_times.time(Prayer::FajrTomorrow) > proportional_times.time(Prayer::Isha)
+        );
+        assert!(proportional_times.time(Prayer::Isha) < proportional_times.time(Prayer::Qiyam));
+        assert!(
+            proportional_times.time(Prayer::Qiyam) < proportional_times.time(Prayer::FajrTomorrow)
+        );
+    }
+
+    #[test]
+    fn makruh_windows_of_the_day() {
+        let date = Utc.ymd(2015, 7, 12);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let times = PrayerTimes::new(date, coordinates, params);
+        let windows = times.makruh_windows();
+        let reasons: Vec<Reason> = windows.iter().map(|(reason, _, _)| *reason).collect();
+
+        assert_eq!(
+            reasons,
+            vec![
+                Reason::DuringSunrise,
+                Reason::DuringZawal,
+                Reason::DuringSunset
+            ]
+        );
+        for (reason, start, end) in windows {
+            assert!(start < end);
+            assert_eq!(times.restriction(start), Some(reason));
+            assert_eq!(times.restriction(end), None);
+        }
+    }
+
+    #[test]
+    fn calculate_times_for_several_offsets() {
+        let date = Utc.ymd(2015, 7, 12);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let offsets = [
+            FixedOffset::west(4 * 3600),
+            FixedOffset::east(0),
+            FixedOffset::east(5 * 3600 + 1800),
+        ];
+        let times = PrayerTimes::new(date, coordinates, params);
+        let localized = PrayerTimes::for_offsets(date, coordinates, params, &offsets);
+
+        assert_eq!(localized.len(), 3);
+        for (local_times, offset) in localized.iter().zip(offsets.iter()) {
+            assert_eq!(local_times.time(Prayer::Fajr), times.time(Prayer::Fajr));
+            assert_eq!(local_times.time(Prayer::Isha), times.time(Prayer::Isha));
+            assert_eq!(local_times.time(Prayer::Dhuhr).offset(), offset);
+        }
+        assert_ne!(
+            localized[0].time(Prayer::Dhuhr).format("%H:%M").to_string(),
+            localized[2].time(Prayer::Dhuhr).format("%H:%M").to_string()
+        );
+    }
+
+    #[test]
+    fn calculate_times_with_minimum_gap() {
+        let date = Utc.ymd(2021, 6, 21);
+        let coordinates = Coordinates::new(60.0, 10.75);
+        let params = Configuration::new(18.0, 1.0).done();
+        let spaced_params = Configuration::new(18.0, 1.0).min_gap(5).done();
+        let times = PrayerTimes::new(date, coordinates, params);
+        let spaced_times = PrayerTimes::new(date, coordinates, spaced_params);
+        let gap = |times: &PrayerTimes| {
+            times
+                .time(Prayer::Isha)
+                .signed_duration_since(times.time(Prayer::Maghrib))
+        };
+
+        assert!(gap(&times) < Duration::minutes(5));
+        assert_eq!(gap(&spaced_times), Duration::minutes(5));
+        assert_eq!(
+            spaced_times.time(Prayer::Maghrib),
+            times.time(Prayer::Maghrib)
+        );
+        assert_eq!(spaced_times.time(Prayer::Dhuhr), times.time(Prayer::Dhuhr));
+    }
+
+    #[test]
+    fn calculate_times_with_isha_before_maghrib() {
+        let date = Utc.ymd(2015, 7, 12);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let mut early_params = params;
+        early_params.adjustments = Adjustment::new().isha(-180).done();
+        let times = PrayerTimes::new(date, coordinates, params);
+        let early_times = PrayerTimes::new(date, coordinates, early_params);
+
+        assert!(!times.is_clamped());
+        assert!(early_times.is_clamped());
+        assert_eq!(
+            early_times.time(Prayer::Isha),
+            early_times.time(Prayer::Maghrib)
+        );
+        assert_eq!(
+            early_times.time(Prayer::Maghrib),
+            times.time(Prayer::Maghrib)
+        );
+
+        early_params.min_isha_gap_minutes = 5;
+        let spaced_times = PrayerTimes::new(date, coordinates, early_params);
+
+        assert_eq!(
+            spaced_times
+                .time(Prayer::Isha)
+                .signed_duration_since(spaced_times.time(Prayer::Maghrib)),
+            Duration::minutes(5)
+        );
+    }
+
+    #[test]
+    fn night_portion_durations_at_high_latitude() {
+        let date = Utc.ymd(2021, 3, 1);
+        let coordinates = Coordinates::new(55.0, -3.0);
+        let params = Configuration::new(18.0, 17.0)
+            .fajr_high_latitude_rule(HighLatitudeRule::SeventhOfTheNight)
+            .isha_high_latitude_rule(HighLatitudeRule::MiddleOfTheNight)
+            .done();
+        let times = PrayerTimes::new(date, coordinates, params);
+        let solar_time = SolarTime::new(date.and_hms(0, 0, 0), coordinates).unwrap();
+        let solar_time_tomorrow =
+            SolarTime::new(date.and_hms(0, 0, 0).tomorrow(), coordinates).unwrap();
+        let night = solar_time_tomorrow
+            .sunrise
+            .signed_duration_since(solar_time.sunset);
+        let (fajr_duration, isha_duration) = times.night_portion_durations();
+
+        assert!(fajr_duration > Duration::zero());
+        assert!(isha_duration > Duration::zero());
+        assert_eq!(fajr_duration, Duration::seconds(night.num_seconds() / 7));
+        assert_eq!(isha_duration, Duration::seconds(night.num_seconds() / 2));
+    }
+
+    #[test]
+    fn calculate_times_for_hijri_date() {
+        let hijri = Hijri::new(1446, 9, 15);
+        let date = hijri.to_gregorian();
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let hijri_times = PrayerSchedule::new()
+            .with_hijri_date(hijri)
+            .for_location(coordinates)
+            .with_configuration(params)
+            .calculate()
+            .unwrap();
+        let gregorian_times = PrayerSchedule::new()
+            .on(Utc.ymd(date.year(), date.month(), date.day()))
+            .for_location(coordinates)
+            .with_configuration(params)
+            .calculate()
+            .unwrap();
+
+        assert_eq!(date, NaiveDate::from_ymd(2025, 3, 15));
+        assert_eq!(hijri_times, gregorian_times);
+    }
+
+    #[test]
+    fn last_third_of_the_night_duration() {
+        let date = Utc.ymd(2015, 7, 12);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let times = PrayerTimes::new(date, coordinates, params);
+        let night = times
+            .time(Prayer::FajrTomorrow)
+            .signed_duration_since(times.time(Prayer::Maghrib));
+        let difference = times.last_third_duration() - night / 3;
+
+        assert!(difference.num_seconds().abs() <= 60);
+    }
+
+    #[test]
+    fn calculate_times_for_tomorrow() {
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let offset = FixedOffset::west(4 * 3600);
+        let today = PrayerTimes::new(Utc.ymd(2015, 7, 12), coordinates, params);
+        let next_day = PrayerTimes::new(Utc.ymd(2015, 7, 13), coordinates, params);
+        let tomorrow = today.with_timezone(&offset).tomorrow();
+
+        assert_eq!(tomorrow.time(Prayer::Fajr), next_day.time(Prayer::Fajr));
+        assert_eq!(tomorrow.time(Prayer::Isha), next_day.time(Prayer::Isha));
+        assert_eq!(
+            tomorrow.time(Prayer::Fajr),
+            today.time(Prayer::FajrTomorrow)
+        );
+        assert_eq!(tomorrow.time(Prayer::Fajr).offset(), &offset);
+    }
+
+    #[test]
+    fn calculate_asr_with_maximum_fraction() {
+        let date = Utc.ymd(2021, 12, 21);
+        let coordinates = Coordinates::new(60.0, 10.75);
+        let params = Configuration::new(18.0, 17.0).madhab(Madhab::Hanafi).done();
+        let capped_params = Configuration::new(18.0, 17.0)
+            .madhab(Madhab::Hanafi)
+            .asr_max_fraction(0.3)
+            .done();
+        let times = PrayerTimes::new(date, coordinates, params);
+        let capped_times = PrayerTimes::new(date, coordinates, capped_params);
+        let dhuhr = times.time(Prayer::Dhuhr);
+        let interval = times.time(Prayer::Maghrib) - dhuhr;
+        let latest = dhuhr + Duration::seconds((0.3 * interval.num_seconds() as f64) as i64);
+
+        assert!(times.time(Prayer::Asr) > latest);
+        assert_eq!(
+            capped_times.time(Prayer::Asr),
+            latest.rounded_minute(Rounding::Nearest)
+        );
+        assert_eq!(
+            capped_times.time(Prayer::Maghrib),
+            times.time(Prayer::Maghrib)
+        );
+    }
+
+    #[test]
+    fn calculate_times_without_method_adjustments() {
+        let date = Utc.ymd(2015, 7, 12);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let raw_params = params.without_method_adjustments();
+        let times = PrayerTimes::new(date, coordinates, params);
+        let raw_times = PrayerTimes::new(date, coordinates, raw_params);
+
+        assert_eq!(params.method_adjustments.dhuhr, 1);
+        assert_eq!(raw_params.method_adjustments, TimeAdjustment::default());
+        assert_eq!(
+            raw_times.time(Prayer::Dhuhr),
+            times.time(Prayer::Dhuhr) - Duration::minutes(1)
+        );
+        assert_eq!(raw_times.time(Prayer::Asr), times.time(Prayer::Asr));
+    }
+
+    #[test]
+    fn calculate_isha_with_duration_after_maghrib() {
+        let date = Utc.ymd(2015, 7, 12);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let minutes_params = Configuration::new(18.0, 0.0).isha_interval(90).done();
+        let duration_params = Configuration::new(18.0, 17.0)
+            .isha_after_maghrib(Duration::hours(1) + Duration::minutes(30))
+            .done();
+        let minutes_times = PrayerTimes::new(date, coordinates, minutes_params);
+        let duration_times = PrayerTimes::new(date, coordinates, duration_params);
+
+        assert_eq!(
+            duration_params.isha_interval_duration(),
+            Duration::minutes(90)
+        );
+        assert_eq!(
+            duration_times.time(Prayer::Isha),
+            minutes_times.time(Prayer::Isha)
+        );
+
+        let mut precise_params = Configuration::new(18.0, 17.0)
+            .isha_after_maghrib(Duration::minutes(90) + Duration::seconds(40))
+            .done();
+        precise_params.rounding = Rounding::None;
+        let mut raw_params = minutes_params;
+        raw_params.rounding = Rounding::None;
+
+        assert_eq!(
+            PrayerTimes::new(date, coordinates, precise_params).time(Prayer::Isha),
+            PrayerTimes::new(date, coordinates, raw_params).time(Prayer::Isha)
+                + Duration::seconds(40)
+        );
+    }
+
+    #[test]
+    fn calculate_times_at_the_north_pole() {
+        let north_pole = Coordinates::new(90.0, 0.0);
+        let params = Configuration::with(Method::MuslimWorldLeague, Madhab::Shafi);
+
+        assert_eq!(
+            PrayerTimes::try_new(Utc.ymd(2021, 6, 21), north_pole, params),
+            Err(SolarError::MidnightSun)
+        );
+        assert_eq!(
+            PrayerTimes::try_new(Utc.ymd(2021, 12, 21), north_pole, params),
+            Err(SolarError::PolarNight)
+        );
+    }
+
+    #[test]
+    fn calculate_month_as_json() {
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let july = month_json(2015, 7, coordinates, params).unwrap();
+        let february = month_json(2016, 2, coordinates, params).unwrap();
+
+        assert!(july.starts_with("[{\"date\":\"2015-07-01\",\"fajr\":\"2015-07-01T"));
+        assert!(july.ends_with("}]"));
+        assert_eq!(july.split("},{").count(), 31);
+        assert_eq!(july.matches("\"isha\":").count(), 31);
+        assert!(july.contains("{\"date\":\"2015-07-12\",\"fajr\":\"2015-07-12T08:42:"));
+        assert_eq!(february.split("},{").count(), 29);
+        assert!(month_json(2015, 13, coordinates, params).is_err());
+        assert!(month_json(2015, 6, Coordinates::new(89.0, 0.0), params).is_err());
+    }
+
+    #[test]
+    fn calculate_one_seventh_above_55_with_any_method() {
+        let date = Utc.ymd(2021, 6, 21);
+        let coordinates = Coordinates::new(58.0, -3.0);
+        let params = Configuration::with(Method::MuslimWorldLeague, Madhab::Shafi);
+        let mut seventh_params = params;
+        seventh_params.one_seventh_above_55 = true;
+
+        let times = PrayerTimes::new(date, coordinates, params);
+        let seventh_times = PrayerTimes::new(date, coordinates, seventh_params);
+        let night = SolarTime::new(date.succ().and_hms(0, 0, 0), coordinates)
+            .unwrap()
+            .sunrise
+            - SolarTime::new(date.and_hms(0, 0, 0), coordinates)
+                .unwrap()
+                .sunset;
+        let seventh = (night.num_seconds() / 7) as f64 / 60.0;
+        let before_sunrise = seventh_times.time(Prayer::Sunrise) - seventh_times.time(Prayer::Fajr);
+        let after_sunset = seventh_times.time(Prayer::Isha) - seventh_times.time(Prayer::Maghrib);
+
+        assert!((before_sunrise.num_minutes() as f64 - seventh).abs() <= 1.0);
+        assert!((after_sunset.num_minutes() as f64 - seventh).abs() <= 1.0);
+        assert!(times.time(Prayer::Fajr) < seventh_times.time(Prayer::Fajr));
+        assert!(times.time(Prayer::Isha) > seventh_times.time(Prayer::Isha));
+    }
+
+    #[test]
+    fn calculate_with_high_latitude_divisor() {
+        let date = Utc.ymd(2021, 6, 21);
+        let coordinates = Coordinates::new(58.0, -3.0);
+        let seventh_params = Configuration::new(18.0, 18.0)
+            .method(Method::MoonsightingCommittee)
+            .one_seventh_above_55(true)
+            .done();
+        let sixth_params = Configuration::new(18.0, 18.0)
+            .method(Method::MoonsightingCommittee)
+            .one_seventh_above_55(true)
+            .high_latitude_divisor(6)
+            .done();
+        let seventh_times = PrayerTimes::new(date, coordinates, seventh_params);
+        let sixth_times = PrayerTimes::new(date, coordinates, sixth_params);
+        let night = SolarTime::new(date.succ().and_hms(0, 0, 0), coordinates)
+            .unwrap()
+            .sunrise
+            - SolarTime::new(date.and_hms(0, 0, 0), coordinates)
+                .unwrap()
+                .sunset;
+        let difference = (night.num_seconds() / 6 - night.num_seconds() / 7) as f64 / 60.0;
+        let fajr_difference = seventh_times.time(Prayer::Fajr) - sixth_times.time(Prayer::Fajr);
+        let isha_difference = sixth_times.time(Prayer::Isha) - seventh_times.time(Prayer::Isha);
+
+        assert_eq!(seventh_params.high_latitude_divisor, 7);
+        assert!((fajr_difference.num_minutes() as f64 - difference).abs() <= 1.0);
+        assert!((isha_difference.num_minutes() as f64 - difference).abs() <= 1.0);
+    }
+
+    #[test]
+    fn prayer_clock_recalculates_when_the_day_changes() {
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let edt = FixedOffset::west(4 * 3600);
+        let mut clock = PrayerClock::new(coordinates, params, &edt);
+        let times = PrayerTimes::new(Utc.ymd(2015, 7, 12), coordinates, params).with_timezone(&edt);
+
+        assert_eq!(
+            clock.tick(edt.ymd(2015, 7, 12).and_hms(12, 0, 0)),
+            Some((Prayer::Dhuhr, times.time(Prayer::Dhuhr)))
+        );
+        assert_eq!(
+            clock.tick(edt.ymd(2015, 7, 12).and_hms(23, 59, 0)),
+            Some((Prayer::Qiyam, times.time(Prayer::Qiyam)))
+        );
+        assert_eq!(clock.times.as_ref().unwrap().date, times.date);
+
+        let tomorrow =
+            PrayerTimes::new(Utc.ymd(2015, 7, 13), coordinates, params).with_timezone(&edt);
+
+        assert_eq!(
+            clock.tick(edt.ymd(2015, 7, 13).and_hms(0, 1, 0)),
+            Some((Prayer::Fajr, tomorrow.time(Prayer::Fajr)))
+        );
+        assert_eq!(
+            clock.tick(edt.ymd(2015, 7, 13).and_hms(9, 0, 0)),
+            Some((Prayer::Dhuhr, tomorrow.time(Prayer::Dhuhr)))
+        );
+        assert_eq!(clock.times.as_ref().unwrap().date, tomorrow.date);
+    }
+
+    #[test]
+    fn calculate_isha_with_fixed_clock_time() {
+        let date = Utc.ymd(2021, 6, 21);
+        let oslo = Coordinates::new(60.0, 10.7522);
+        let cest = FixedOffset::east(2 * 3600);
+        let params = Configuration::with(Method::MuslimWorldLeague, Madhab::Shafi);
+        let fixed_params = Configuration::new(18.0, 17.0)
+            .method(Method::MuslimWorldLeague)
+            .isha_fixed_time(NaiveTime::from_hms(23, 0, 0), cest)
+            .done();
+        let times = PrayerTimes::new(date, oslo, params);
+        let fixed_times = PrayerTimes::new(date, oslo, fixed_params);
+
+        assert_eq!(
+            fixed_times.time(Prayer::Isha).with_timezone(&cest),
+            cest.ymd(2021, 6, 21).and_hms(23, 0, 0)
+        );
+        assert!(times.time(Prayer::Isha) > fixed_times.time(Prayer::Isha));
+
+        // The Isha adjustments apply to the fixed time as well.
+        let mut adjusted_params = fixed_params;
+        adjusted_params.adjustments = Adjustment::new().isha(5).done();
+        adjusted_params.safety_margin_minutes = 2;
+
+        assert_eq!(
+            PrayerTimes::new(date, oslo, adjusted_params)
+                .time(Prayer::Isha)
+                .with_timezone(&cest),
+            cest.ymd(2021, 6, 21).and_hms(23, 7, 0)
+        );
+
+        // In the winter the astronomical Isha is used.
+        let winter = Utc.ymd(2021, 12, 21);
+
+        assert_eq!(
+            PrayerTimes::new(winter, oslo, fixed_params).time(Prayer::Isha),
+            PrayerTimes::new(winter, oslo, params).time(Prayer::Isha)
+        );
+    }
+
+    #[test]
+    fn calculate_moonsighting_committee_in_the_southern_hemisphere() {
+        let cape_town = Coordinates::new(-33.9249, 18.4241);
+        let params = Configuration::with(Method::MoonsightingCommittee, Madhab::Shafi);
+
+        // The seasonal twilight, in minutes, of the Moonsighting Committee
+        // at 33.9249°S; the southern year starts at the June solstice.
+        // Fajr and Isha are at most this far from sunrise and sunset.
+        let seasons = [
+            (Utc.ymd(2021, 3, 20), 87.53, 75.81),
+            (Utc.ymd(2021, 6, 21), 92.67, 90.79),
+            (Utc.ymd(2021, 9, 22), 87.35, 75.96),
+            (Utc.ymd(2021, 12, 21), 104.67, 78.79),
+        ];
+
+        for (date, morning, evening) in seasons.iter() {
+            let times = PrayerTimes::new(*date, cape_town, params);
+            let fajr = times.time(Prayer::Sunrise) - times.time(Prayer::Fajr);
+            let isha = times.time(Prayer::Isha) - times.time(Prayer::Maghrib);
+
+            // The times are rounded to the minute.
+            assert!(fajr.num_minutes() as f64 <= morning + 1.0);
+            assert!(isha.num_minutes() as f64 <= evening + 1.0);
+            assert!(fajr.num_minutes() > 80);
+            assert!(isha.num_minutes() > 70);
+        }
+
+        // At the December solstice, the 18° Fajr is earlier
+        // than the seasonal limit, which is used instead.
+        let summer = PrayerTimes::new(Utc.ymd(2021, 12, 21), cape_town, params);
+        let fajr = summer.time(Prayer::Sunrise) - summer.time(Prayer::Fajr);
+
+        assert!((fajr.num_minutes() as f64 - 104.67).abs() <= 1.0);
+    }
+
+    #[test]
+    fn calculate_maghrib_with_delay_after_sunset() {
+        let date = Utc.ymd(2015, 7, 12);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::new(18.0, 17.0)
+            .rounding(Rounding::None)
+            .maghrib_delay(4)
+            .done();
+        let times = PrayerTimes::new(date, coordinates, params);
+        let sunset = SolarTime::new(date.and_hms(0, 0, 0), coordinates)
+            .unwrap()
+            .sunset;
+
+        assert_eq!(times.time(Prayer::Maghrib), sunset + Duration::minutes(4));
+    }
+
+    #[test]
+    fn weekly_timetable_for_a_week() {
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let edt = FixedOffset::west(4 * 3600);
+        let timetable =
+            WeeklyTimetable::for_week(edt.ymd(2015, 7, 13).and_hms(0, 0, 0), coordinates, params);
+        let friday =
+            PrayerTimes::new(Utc.ymd(2015, 7, 17), coordinates, params).with_timezone(&edt);
+        let display = timetable.to_string();
+        let rows: Vec<&str> = display.lines().collect();
+
+        assert_eq!(timetable.get(Weekday::Fri), Some(&friday));
+        assert_eq!(rows.len(), 7);
+        assert!(rows[0].starts_with("Mon | Fajr "));
+        assert!(rows[0].contains(" | Dhuhr "));
+        assert!(rows[4].starts_with("Fri | Fajr "));
+        assert!(rows[4].contains(&format!(
+            " | Jumua {} |",
+            friday.time(Prayer::Dhuhr).format("%H:%M")
+        )));
+    }
+
+    #[test]
+    fn weekly_timetable_from_iterator() {
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let saturday = PrayerTimes::new(Utc.ymd(2015, 7, 18), coordinates, params);
+        let sunday = PrayerTimes::new(Utc.ymd(2015, 7, 19), coordinates, params);
+        let mut timetable: WeeklyTimetable = vec![saturday].into_iter().collect();
+
+        assert_eq!(timetable.get(Weekday::Sat), Some(&saturday));
+        assert_eq!(timetable.get(Weekday::Sun), None);
+        assert_eq!(timetable.to_string().lines().count(), 1);
+
+        timetable.extend(vec![sunday]);
+
+        assert_eq!(timetable.get(Weekday::Sun), Some(&sunday));
+    }
+
+    #[test]
+    fn calculate_times_on_the_local_date_near_the_date_line() {
+        // Kiritimati is at UTC+14, far east of its meridian.
+        let date = Utc.ymd(2021, 7, 12);
+        let kiritimati = Coordinates::new(1.8721, -157.4278);
+        let lint = FixedOffset::east(14 * 3600);
+        let params = Configuration::with(Method::MuslimWorldLeague, Madhab::Shafi);
+        let mut local_params = params;
+        local_params.solar_day_basis = SolarDayBasis::LocalMidnight(lint);
+        let utc_times = PrayerTimes::new(date, kiritimati, params).with_timezone(&lint);
+        let local_times = PrayerTimes::new(date, kiritimati, local_params).with_timezone(&lint);
+
+        // Based on the UTC date, noon is on the next local date.
+        assert_eq!(utc_times.time(Prayer::Dhuhr).day(), 13);
+
+        for prayer in [Prayer::Fajr, Prayer::Dhuhr, Prayer::Maghrib] {
+            assert_eq!(local_times.time(prayer).day(), 12);
+            assert_eq!(
+                local_times.time(prayer),
+                PrayerTimes::new(date.pred(), kiritimati, params).time(prayer)
+            );
+        }
+        assert!(local_times.is_after_islamic_midnight(local_times.time(Prayer::Isha)));
+        assert_eq!(local_times.tomorrow().time(Prayer::Dhuhr).day(), 13);
+        assert_eq!(
+            local_times.restriction(local_times.time(Prayer::Dhuhr) - Duration::minutes(2)),
+            Some(Reason::DuringZawal)
+        );
+
+        // Nothing changes where the local and UTC dates agree at noon.
+        let raleigh = Coordinates::new(35.7750, -78.6336);
+        let na_params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let mut edt_params = na_params;
+        edt_params.solar_day_basis = SolarDayBasis::LocalMidnight(FixedOffset::west(4 * 3600));
+
+        assert_eq!(
+            PrayerTimes::new(Utc.ymd(2015, 7, 12), raleigh, edt_params).time(Prayer::Dhuhr),
+            PrayerTimes::new(Utc.ymd(2015, 7, 12), raleigh, na_params).time(Prayer::Dhuhr)
+        );
+    }
+
+    #[test]
+    fn calculate_precise_asr_near_the_equinox() {
+        let date = Utc.ymd(2021, 3, 20);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::new(18.0, 17.0)
+            .madhab(Madhab::Hanafi)
+            .rounding(Rounding::None)
+            .done();
+        let precise_params = Configuration::new(18.0, 17.0)
+            .madhab(Madhab::Hanafi)
+            .rounding(Rounding::None)
+            .precise_asr(true)
+            .done();
+        let asr = PrayerTimes::new(date, coordinates, params).time(Prayer::Asr);
+        let precise_asr = PrayerTimes::new(date, coordinates, precise_params).time(Prayer::Asr);
+        let difference = precise_asr.signed_duration_since(asr).num_seconds().abs();
+
+        assert!(difference > 0);
+        assert!(difference < 60);
+    }
+
+    #[test]
+    fn calculate_midnight_for_both_night_definitions() {
+        let date = Utc.ymd(2015, 7, 12);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::new(18.0, 17.0).done();
+        let sunrise_params = Configuration::new(18.0, 17.0)
+            .night_definition(NightDefinition::SunsetToSunrise)
+            .done();
+        let times = PrayerTimes::new(date, coordinates, params);
+        let sunrise_times = PrayerTimes::new(date, coordinates, sunrise_params);
+        let solar_time = SolarTime::new(date.and_hms(0, 0, 0), coordinates).unwrap();
+        let solar_time_tomorrow =
+            SolarTime::new(date.succ().and_hms(0, 0, 0), coordinates).unwrap();
+
+        assert_eq!(
+            times.night_span(),
+            (
+                times.time(Prayer::Maghrib),
+                times.time(Prayer::FajrTomorrow)
+            )
+        );
+        assert_eq!(
+            sunrise_times.night_span(),
+            (solar_time.sunset, solar_time_tomorrow.sunrise)
+        );
+
+        for times in [times, sunrise_times] {
+            let (start, end) = times.night_span();
+            let middle = start + (end - start) / 2;
+
+            assert_eq!(
+                times.middle_of_the_night(),
+                middle.rounded_minute(Rounding::Nearest)
+            );
+        }
+
+        // The night ends later at sunrise than at Fajr.
+        assert!(sunrise_times.middle_of_the_night() > times.middle_of_the_night());
+        assert_eq!(sunrise_times.time(Prayer::Qiyam), times.time(Prayer::Qiyam));
+    }
+
+    #[test]
+    fn calculate_sunrise_with_custom_altitude() {
+        let date = Utc.ymd(2015, 7, 12);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::new(18.0, 17.0)
+            .rounding(Rounding::None)
+            .done();
+        let center_params = Configuration::new(18.0, 17.0)
+            .rounding(Rounding::None)
+            .sunrise_altitude(Angle::new(0.0))
+            .done();
+        let times = PrayerTimes::new(date, coordinates, params);
+        let center_times = PrayerTimes::new(date, coordinates, center_params);
+        let sunrise_delay = center_times.time(Prayer::Sunrise) - times.time(Prayer::Sunrise);
+        let sunset_advance = times.time(Prayer::Maghrib) - center_times.time(Prayer::Maghrib);
+
+        assert_eq!(params.sunrise_altitude, Angle::SUN_HORIZON_ALTITUDE);
+        assert!(sunrise_delay > Duration::minutes(3));
+        assert!(sunrise_delay < Duration::minutes(6));
+        assert!(sunset_advance > Duration::minutes(3));
+        assert!(sunset_advance < Duration::minutes(6));
+        assert_eq!(
+            center_params.angle_for(Prayer::Sunrise),
+            Some(Angle::new(0.0))
+        );
+    }
 }